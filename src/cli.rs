@@ -1,13 +1,62 @@
 use clap::{Args, Parser, Subcommand};
+use std::path::PathBuf;
 
 #[derive(Parser)]
 #[command(author, version, about, long_about = None)]
 #[command(propagate_version = true)]
 pub struct Cli {
+    #[command(flatten)]
+    pub global: GlobalArgs,
+
     #[command(subcommand)]
     pub command: Commands,
 }
 
+// Options accepted by every subcommand. A doc comment here would replace
+// the top-level about text, since clap reads it from the flattened struct.
+#[derive(Args, Clone, Default)]
+pub struct GlobalArgs {
+    /// GitHub REST API version to request (overrides config, e.g. 2022-11-28)
+    #[arg(long, global = true)]
+    pub api_version: Option<String>,
+
+    /// GitHub REST API base URL, for Enterprise Server (overrides config api_base_url)
+    #[arg(long, global = true, value_name = "URL")]
+    pub api_url: Option<String>,
+
+    /// Read the GitHub token from a file (also settable via REPOGEN_TOKEN_FILE)
+    #[arg(long, global = true)]
+    pub token_file: Option<PathBuf>,
+
+    /// PEM file with extra CA certificates to trust (overrides config ca_bundle)
+    #[arg(long, global = true)]
+    pub ca_bundle: Option<PathBuf>,
+
+    /// Skip TLS certificate verification (dangerous, for debugging only)
+    #[arg(long, global = true, default_value_t = false)]
+    pub insecure: bool,
+
+    /// Config profile to use, e.g. work or personal (default: the active profile)
+    #[arg(long, global = true)]
+    pub profile: Option<String>,
+
+    /// Answer confirmation prompts automatically (see docs/USAGE.md for the answers used)
+    #[arg(
+        short = 'y',
+        long,
+        visible_alias = "yes",
+        global = true,
+        default_value_t = false
+    )]
+    pub assume_yes: bool,
+
+    /// Log each API request to stderr; repeat for more detail (-vv error response bodies, -vvv request headers)
+    #[arg(short = 'v', long, global = true, action = clap::ArgAction::Count)]
+    pub verbose: u8,
+}
+
+// Parsed once per run, so the size of `New` doesn't matter
+#[allow(clippy::large_enum_variant)]
 #[derive(Subcommand)]
 pub enum Commands {
     // TODO: Call this login instead?
@@ -19,6 +68,43 @@ pub enum Commands {
 
     /// View and edit configuration
     Config(Config),
+
+    /// Clone existing repositories from GitHub
+    Clone(Clone),
+
+    /// Update metadata of an existing repository
+    #[command(visible_alias = "edit")]
+    Update(Update),
+
+    /// Transfer a repository to another user or organization
+    Transfer(Transfer),
+
+    /// Rename an existing repository
+    Rename(Rename),
+
+    /// Archive a repository (read-only), or unarchive it
+    Archive(Archive),
+
+    /// List your existing repositories
+    List(List),
+
+    /// Create a new project step by step with an interactive wizard
+    Bootstrap(Bootstrap),
+
+    /// List config profiles and switch between them
+    Profile(Profile),
+
+    /// Save and manage presets of `new` flags
+    Template(Template),
+
+    /// Show the GitHub account the configured token belongs to
+    Whoami(Whoami),
+
+    /// Show the repository `new` created most recently
+    Last(Last),
+
+    /// Check configuration, authentication and connectivity
+    Doctor(Doctor),
 }
 
 #[derive(Args)]
@@ -30,31 +116,61 @@ pub struct Init {
     /// Argument to force only meta data setup (profile, preferences)
     #[arg(short, long = "meta", default_value_t = false)]
     pub metadata: bool,
+
+    /// Change a locked configuration anyway
+    #[arg(long, default_value_t = false)]
+    pub force: bool,
+
+    /// Simulate authentication without contacting GitHub, saving a placeholder token
+    #[arg(long, default_value_t = false, requires = "authentication")]
+    pub dry_run: bool,
+
+    /// Only walk through creating a GitHub OAuth app and save its client ID
+    #[arg(long, default_value_t = false, conflicts_with_all = ["authentication", "metadata"])]
+    pub oauth_setup: bool,
+
+    /// Merge settings from an existing config file, e.g. one copied from another machine
+    #[arg(long, value_name = "PATH", conflicts_with_all = ["authentication", "metadata", "oauth_setup"])]
+    pub import: Option<PathBuf>,
+
+    /// Print usage examples for this command and exit
+    #[arg(long, default_value_t = false, exclusive = true)]
+    pub examples: bool,
 }
 
-#[derive(Args)]
+#[derive(Args, Clone, Default)]
 pub struct New {
     /// Name of the new repository
-    pub name: String,
+    #[arg(required_unless_present = "examples")]
+    pub name: Option<String>,
 
     /// Description of the new repository
     #[arg(short, long = "desc")]
     pub description: Option<String>,
 
-    /// Make the repository private (overrides config default)
-    #[arg(short, long)]
+    /// Language code picking a description from config description_templates
+    #[arg(long, conflicts_with = "description")]
+    pub lang: Option<String>,
+
+    /// Deprecated: use --visibility private
+    #[arg(short, long, num_args = 0..=1, default_missing_value = "true")]
     pub private: Option<bool>,
 
-    /// Make the repository public (overrides config default)
-    #[arg(long)]
+    /// Deprecated: use --visibility public
+    #[arg(long, num_args = 0..=1, default_missing_value = "true")]
     pub public: Option<bool>,
 
+    /// Who can see the repository: public, private, or internal (organizations on
+    /// GitHub Enterprise) (overrides config default)
+    #[arg(long, value_parser = ["public", "private", "internal"], conflicts_with_all = ["private", "public"])]
+    pub visibility: Option<String>,
+
     /// License to use (overrides config default)
     /// Options: MIT, Apache-2.0, GPL-3.0, BSD-3-Clause, Unlicense, or None
     #[arg(short, long)]
     pub license: Option<String>,
 
-    /// .gitignore template to use (overrides config default)
+    /// .gitignore template to use (overrides config default); comma-separate to combine several
     /// Options: Node, Python, Rust, Go, Java, C++, Swift, or None
     #[arg(short, long)]
     pub gitignore: Option<String>,
@@ -62,12 +178,385 @@ pub struct New {
     /// Initialize with README
     #[arg(long, default_value_t = true)]
     pub readme: bool,
+
+    /// Replace the initial README with generated content (basic or detailed)
+    #[arg(long, value_name = "TEMPLATE", value_parser = ["basic", "detailed"])]
+    pub readme_template: Option<String>,
+
+    /// Commit a built-in .gitattributes template: lf, lfs-images, or None (overrides config default)
+    #[arg(long, value_name = "TEMPLATE", value_parser = ["lf", "lfs-images", "None"])]
+    pub gitattributes: Option<String>,
+
+    /// Enable secret scanning after creation (overrides config default)
+    #[arg(long, default_value_t = false)]
+    pub enable_secret_scanning: bool,
+
+    /// Enable secret scanning push protection after creation (overrides config default)
+    #[arg(long, default_value_t = false)]
+    pub enable_push_protection: bool,
+
+    /// Create the repository with issues turned off
+    #[arg(long, default_value_t = false)]
+    pub no_issues: bool,
+
+    /// Create the repository with the wiki turned off
+    #[arg(long, default_value_t = false)]
+    pub no_wiki: bool,
+
+    /// Create the repository with projects turned off
+    #[arg(long, default_value_t = false)]
+    pub no_projects: bool,
+
+    /// Commit message for files repogen commits after creation ({name} = repo name)
+    #[arg(long)]
+    pub commit_message: Option<String>,
+
+    /// Pause and retry when GitHub reports a secondary rate limit
+    #[arg(long, default_value_t = false)]
+    pub retry_on_secondary_limit: bool,
+
+    /// Secondary rate limit retries, 0-10; a non-zero value enables retrying (overrides config max_retries)
+    #[arg(long, value_parser = clap::value_parser!(u32).range(0..=10))]
+    pub max_retries: Option<u32>,
+
+    /// First retry pause in milliseconds, doubled on each retry (overrides config retry_delay_ms)
+    #[arg(long, value_parser = clap::value_parser!(u64).range(100..=600_000))]
+    pub retry_delay_ms: Option<u64>,
+
+    /// If GitHub rejects the token during creation, offer to sign in again and retry
+    #[arg(long, default_value_t = false)]
+    pub retry_auth: bool,
+
+    /// Commit author name for this run (overrides config user_name)
+    #[arg(long)]
+    pub author_name: Option<String>,
+
+    /// Commit author email for this run (overrides config user_email)
+    #[arg(long)]
+    pub author_email: Option<String>,
+
+    /// Leave the cloned repository's git config alone (overrides config default)
+    #[arg(long, default_value_t = false, conflicts_with_all = ["author_name", "author_email", "commit_sign"])]
+    pub no_git_config: bool,
+
+    /// Enable commit signing in the cloned repository (overrides config default)
+    #[arg(long, default_value_t = false)]
+    pub commit_sign: bool,
+
+    /// Print the README that would be generated and exit without creating anything
+    #[arg(long, default_value_t = false)]
+    pub preview_readme: bool,
+
+    /// Only print the new repository's URL; errors still go to stderr
+    #[arg(short, long, default_value_t = false)]
+    pub quiet: bool,
+
+    /// Print the created repository as JSON on stdout instead of progress output
+    #[arg(long, default_value_t = false, conflicts_with_all = ["quiet", "preview_readme", "dry_run"])]
+    pub json: bool,
+
+    /// Print the request that would be sent to GitHub and exit without creating anything
+    #[arg(long, default_value_t = false, conflicts_with = "preview_readme")]
+    pub dry_run: bool,
+
+    /// Combine .gitignore templates for these languages, or detect them from the current directory when none are given
+    #[arg(
+        long,
+        num_args = 0..,
+        value_delimiter = ',',
+        value_name = "LANGUAGES",
+        conflicts_with_all = ["gitignore", "import_url"]
+    )]
+    pub gitignore_from_languages: Option<Vec<String>>,
+
+    /// Commit a .gitignore from a raw URL or gist ID, layered on top of any selected template
+    #[arg(
+        long,
+        value_name = "URL|GIST_ID",
+        conflicts_with_all = ["import_url", "template", "local_init"]
+    )]
+    pub from_gitignore_url: Option<String>,
+
+    /// Topic to add after creation; repeat for several
+    #[arg(short, long)]
+    pub topic: Vec<String>,
+
+    /// Rename the initial branch, e.g. to develop or trunk (overrides config default_branch)
+    #[arg(long, value_name = "NAME")]
+    pub default_branch: Option<String>,
+
+    /// Protect the default branch: changes go through pull requests with approving reviews
+    #[arg(long, default_value_t = false)]
+    pub protect: bool,
+
+    /// Approving reviews --protect requires, 0-6 (overrides config required_reviews, default 1)
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u8).range(0..=6))]
+    pub require_reviews: Option<u8>,
+
+    /// Make --protect also require a review from a code owner (overrides config default)
+    #[arg(long, default_value_t = false)]
+    pub require_code_owner_reviews: bool,
+
+    /// Make --protect dismiss approvals when new commits are pushed (overrides config default)
+    #[arg(long, default_value_t = false)]
+    pub dismiss_stale_reviews: bool,
+
+    /// Clone over SSH instead of HTTPS (overrides config clone_protocol)
+    #[arg(long, default_value_t = false)]
+    pub ssh: bool,
+
+    /// Shell command to run inside the clone, e.g. "npm install" (overrides config post_clone_command)
+    #[arg(long, value_name = "CMD")]
+    pub post_clone: Option<String>,
+
+    /// Don't create the config's default_labels in the new repository
+    #[arg(long, default_value_t = false)]
+    pub no_labels: bool,
+
+    /// Clone the repository after creation (overrides config auto_clone)
+    #[arg(long, default_value_t = false, conflicts_with = "no_clone")]
+    pub clone: bool,
+
+    /// Don't clone the repository after creation (overrides config auto_clone)
+    #[arg(long, default_value_t = false)]
+    pub no_clone: bool,
+
+    /// Open the repository in the browser after creation (overrides config auto_open)
+    #[arg(long, default_value_t = false, conflicts_with = "no_open")]
+    pub open: bool,
+
+    /// Don't open the repository in the browser (overrides config auto_open)
+    #[arg(long, default_value_t = false)]
+    pub no_open: bool,
+
+    /// Print the contents of the selected .gitignore template before creating
+    #[arg(long, default_value_t = false)]
+    pub gitignore_preview: bool,
+
+    /// Clone submodules too when auto-cloning (overrides config default)
+    #[arg(long, default_value_t = false)]
+    pub recurse_submodules: bool,
+
+    /// Print the steps to pin the new repository on your GitHub profile (GitHub has no API for it)
+    #[arg(long, default_value_t = false)]
+    pub pin_help: bool,
+
+    /// Wait until the clone URL is served (via `git ls-remote`) before auto-cloning
+    #[arg(long, default_value_t = false)]
+    pub wait_clone_ready: bool,
+
+    /// Create the repository in this organization instead of your account
+    #[arg(long)]
+    pub org: Option<String>,
+
+    /// Grant this organization team push access to the new repository
+    #[arg(long, requires = "org")]
+    pub team: Option<String>,
+
+    /// Shorthand for --org and --team, written as <org>/<team>
+    #[arg(long, conflicts_with_all = ["org", "team"])]
+    pub group: Option<String>,
+
+    /// Succeed without changes when the repository already exists, printing it instead
+    #[arg(long, default_value_t = false, conflicts_with = "dry_run")]
+    pub if_not_exists: bool,
+
+    /// Fill in flags not given here from a preset saved with `repogen template save`
+    #[arg(long, value_name = "NAME")]
+    pub template_preset: Option<String>,
+
+    /// Generate the repository from a GitHub template repository (owner/repo)
+    #[arg(long, value_name = "OWNER/REPO", conflicts_with_all = ["import_url", "readme", "readme_template", "gitignore_from_languages", "preview_readme"])]
+    pub template: Option<String>,
+
+    /// Copy every branch of the --template repository and make this one the default
+    #[arg(long, value_name = "BRANCH", requires = "template")]
+    pub template_branch: Option<String>,
+
+    /// Push an existing local directory to the new, empty repository (runs git init if needed)
+    #[arg(
+        long,
+        value_name = "PATH",
+        conflicts_with_all = ["template", "import_url", "gitignore_from_languages", "readme_template", "preview_readme", "clone"]
+    )]
+    pub local_init: Option<PathBuf>,
+
+    /// Import full history from an external repository (GitLab, Bitbucket, ...)
+    #[arg(long, conflicts_with = "preview_readme")]
+    pub import_url: Option<String>,
+
+    /// Version control system of the import source (detected automatically if omitted)
+    #[arg(long, requires = "import_url", value_parser = ["git", "subversion", "mercurial", "tfvc"])]
+    pub import_vcs: Option<String>,
+
+    /// Username for a private import source
+    #[arg(long, requires = "import_url")]
+    pub import_user: Option<String>,
+
+    /// Password or access token for a private import source
+    #[arg(long, requires = "import_user")]
+    pub import_password: Option<String>,
+
+    /// Print usage examples for this command and exit
+    #[arg(long, default_value_t = false, exclusive = true)]
+    pub examples: bool,
+}
+
+#[derive(Args)]
+pub struct Bootstrap {
+    /// Directory to detect languages from (defaults to the current directory)
+    #[arg(long, value_name = "DIR")]
+    pub path: Option<std::path::PathBuf>,
+
+    /// Print usage examples for this command and exit
+    #[arg(long, default_value_t = false, exclusive = true)]
+    pub examples: bool,
+}
+
+#[derive(Args)]
+pub struct Whoami {
+    /// Print one stable tab-separated line for scripts instead of the details
+    #[arg(long, default_value_t = false)]
+    pub porcelain: bool,
+
+    /// Print usage examples for this command and exit
+    #[arg(long, default_value_t = false, exclusive = true)]
+    pub examples: bool,
+}
+
+#[derive(Args)]
+pub struct Last {
+    /// Open the repository in your browser
+    #[arg(long, default_value_t = false)]
+    pub open: bool,
+
+    /// Print one stable tab-separated line for scripts instead of the details
+    #[arg(long, default_value_t = false)]
+    pub porcelain: bool,
+
+    /// Print usage examples for this command and exit
+    #[arg(long, default_value_t = false, exclusive = true)]
+    pub examples: bool,
+}
+
+#[derive(Args)]
+pub struct Doctor {
+    /// Print usage examples for this command and exit
+    #[arg(long, default_value_t = false, exclusive = true)]
+    pub examples: bool,
+}
+
+#[derive(Args)]
+pub struct Profile {
+    #[command(subcommand)]
+    pub command: Option<ProfileCommand>,
+
+    /// Print usage examples for this command and exit
+    #[arg(long, default_value_t = false, exclusive = true)]
+    pub examples: bool,
+}
+
+#[derive(Subcommand)]
+pub enum ProfileCommand {
+    /// List profiles, marking the active one (the default)
+    List,
+
+    /// Make a profile the one used when --profile isn't given
+    Switch {
+        /// Name of the profile, e.g. work
+        name: String,
+    },
+}
+
+#[derive(Args)]
+pub struct Template {
+    #[command(subcommand)]
+    pub command: Option<TemplateCommand>,
+
+    /// Print usage examples for this command and exit
+    #[arg(long, default_value_t = false, exclusive = true)]
+    pub examples: bool,
+}
+
+#[derive(Subcommand)]
+pub enum TemplateCommand {
+    /// List saved presets (the default)
+    List,
+
+    /// Save flags as a preset for `new --template-preset`
+    Save(TemplateSave),
+
+    /// Show what a preset sets
+    Show {
+        /// Name of the preset
+        name: String,
+    },
+
+    /// Delete a preset
+    Delete {
+        /// Name of the preset
+        name: String,
+    },
+}
+
+#[derive(Args)]
+pub struct TemplateSave {
+    /// Name of the preset, e.g. backend
+    pub name: String,
+
+    /// Description; {name} is replaced with the repository name
+    #[arg(short, long = "desc")]
+    pub description: Option<String>,
+
+    /// Make repositories private
+    #[arg(short, long, default_value_t = false, conflicts_with = "public")]
+    pub private: bool,
+
+    /// Make repositories public
+    #[arg(long, default_value_t = false)]
+    pub public: bool,
+
+    /// License to use, or None
+    #[arg(short, long)]
+    pub license: Option<String>,
+
+    /// .gitignore template to use, or None
+    #[arg(short, long)]
+    pub gitignore: Option<String>,
+
+    /// Topic to add; repeat for several
+    #[arg(short, long)]
+    pub topic: Vec<String>,
+
+    /// Generated README content (basic or detailed)
+    #[arg(long, value_name = "TEMPLATE", value_parser = ["basic", "detailed"])]
+    pub readme_template: Option<String>,
+
+    /// Name of the initial branch
+    #[arg(long, value_name = "NAME")]
+    pub default_branch: Option<String>,
+
+    /// Protect the default branch
+    #[arg(long, default_value_t = false)]
+    pub protect: bool,
+
+    /// Shell command to run inside the clone
+    #[arg(long, value_name = "CMD")]
+    pub post_clone: Option<String>,
+
+    /// Label to create instead of the config's default_labels; repeat for several
+    #[arg(long, value_name = "NAME:COLOR[:DESCRIPTION]")]
+    pub label: Vec<String>,
+
+    /// Replace an existing preset with the same name
+    #[arg(short, long, default_value_t = false)]
+    pub force: bool,
 }
 
 #[derive(Args)]
 pub struct Config {
     /// View the current configuration
-    #[arg(short, long = "view", default_value_t = false)]
+    #[arg(long = "view", default_value_t = false)]
     pub view: bool,
 
     /// Edit the configuration interactively
@@ -77,4 +566,210 @@ pub struct Config {
     /// Clear configuration to default values
     #[arg(short, long = "clear", default_value_t = false)]
     pub clear: bool,
+
+    /// Choose which sections to clear instead of clearing everything (with --clear)
+    #[arg(short, long, default_value_t = false, requires = "clear")]
+    pub interactive: bool,
+
+    /// Detect and interactively repair common configuration problems
+    #[arg(long = "doctor-fix", default_value_t = false)]
+    pub doctor_fix: bool,
+
+    /// Show effective values after environment variable and flag overrides
+    #[arg(long, default_value_t = false, conflicts_with_all = ["edit", "clear", "doctor_fix"])]
+    pub merge_env: bool,
+
+    /// Print `export` statements for the profile, username, host and API version, for `eval`
+    #[arg(long, default_value_t = false, conflicts_with_all = ["view", "edit", "clear", "doctor_fix", "merge_env", "lock", "unlock"])]
+    pub env: bool,
+
+    /// Include the GitHub token in the --env output (prints a warning)
+    #[arg(long, default_value_t = false, requires = "env")]
+    pub include_token: bool,
+
+    /// Set fields without prompting, e.g. --set default_license=MIT (repeatable)
+    #[arg(long, value_name = "KEY=VALUE", num_args = 1.., conflicts_with_all = ["view", "edit", "clear", "doctor_fix", "merge_env", "env", "lock", "unlock"])]
+    pub set: Vec<String>,
+
+    /// Write the configuration to a file without tokens or other secrets
+    #[arg(long, value_name = "PATH", conflicts_with_all = ["view", "edit", "clear", "doctor_fix", "merge_env", "env", "set", "lock", "unlock"])]
+    pub export: Option<PathBuf>,
+
+    /// Keep tokens, the OAuth client ID and the webhook URL in the --export file
+    #[arg(long, default_value_t = false, requires = "export")]
+    pub include_secrets: bool,
+
+    /// Lock the configuration so edits, clears and repairs are refused
+    #[arg(long, default_value_t = false, conflicts_with_all = ["view", "edit", "clear", "doctor_fix", "merge_env", "unlock"])]
+    pub lock: bool,
+
+    /// Unlock the configuration again
+    #[arg(long, default_value_t = false, conflicts_with_all = ["view", "edit", "clear", "doctor_fix", "merge_env"])]
+    pub unlock: bool,
+
+    /// Change a locked configuration anyway
+    #[arg(long, default_value_t = false)]
+    pub force: bool,
+
+    /// Print usage examples for this command and exit
+    #[arg(long, default_value_t = false, exclusive = true)]
+    pub examples: bool,
+}
+
+#[derive(Args)]
+pub struct Clone {
+    /// Repository to clone (name or owner/name; default: the last one `new` created)
+    #[arg(conflicts_with = "all")]
+    pub name: Option<String>,
+
+    /// Clone every repository you own (or every repository of --org)
+    #[arg(long, default_value_t = false)]
+    pub all: bool,
+
+    /// Clone over SSH instead of HTTPS (overrides config clone_protocol)
+    #[arg(long, default_value_t = false)]
+    pub ssh: bool,
+
+    /// Open the clone in your preferred editor
+    #[arg(long, default_value_t = false, conflicts_with = "all")]
+    pub open: bool,
+
+    /// Organization whose repositories should be cloned
+    #[arg(long, requires = "all")]
+    pub org: Option<String>,
+
+    /// Only clone private repositories
+    #[arg(long, default_value_t = false, conflicts_with = "public_only")]
+    pub private_only: bool,
+
+    /// Only clone public repositories
+    #[arg(long, default_value_t = false)]
+    pub public_only: bool,
+
+    /// Number of repositories to clone in parallel
+    #[arg(short, long, default_value_t = 4, value_parser = clap::value_parser!(u8).range(1..=16))]
+    pub jobs: u8,
+
+    /// Print usage examples for this command and exit
+    #[arg(long, default_value_t = false, exclusive = true)]
+    pub examples: bool,
+}
+
+#[derive(Args)]
+pub struct Update {
+    /// Repository to update (name or owner/name)
+    #[arg(required_unless_present = "examples")]
+    pub name: Option<String>,
+
+    /// New description (pass "" to clear it)
+    #[arg(short, long = "desc")]
+    pub description: Option<String>,
+
+    /// Homepage URL (pass "" to clear it)
+    #[arg(long)]
+    pub homepage: Option<String>,
+
+    /// Make the repository private
+    #[arg(long, default_value_t = false, conflicts_with = "public")]
+    pub private: bool,
+
+    /// Make the repository public
+    #[arg(long, default_value_t = false)]
+    pub public: bool,
+
+    /// Make an existing branch the default branch
+    #[arg(long, value_name = "BRANCH")]
+    pub default_branch: Option<String>,
+
+    /// Topic to set; repeat for several. Replaces all existing topics
+    #[arg(short, long)]
+    pub topic: Vec<String>,
+
+    /// Remove all topics
+    #[arg(long, default_value_t = false, conflicts_with = "topic")]
+    pub clear_topics: bool,
+
+    /// Print usage examples for this command and exit
+    #[arg(long, default_value_t = false, exclusive = true)]
+    pub examples: bool,
+}
+
+#[derive(Args)]
+pub struct Rename {
+    /// Repository to rename (name or owner/name)
+    #[arg(required_unless_present = "examples")]
+    pub name: Option<String>,
+
+    /// New name for the repository
+    #[arg(required_unless_present = "examples")]
+    pub new_name: Option<String>,
+
+    /// Print usage examples for this command and exit
+    #[arg(long, default_value_t = false, exclusive = true)]
+    pub examples: bool,
+}
+
+#[derive(Args)]
+pub struct Archive {
+    /// Repository to archive (name or owner/name)
+    #[arg(required_unless_present = "examples")]
+    pub name: Option<String>,
+
+    /// Make an archived repository writable again
+    #[arg(long, default_value_t = false)]
+    pub unarchive: bool,
+
+    /// Print usage examples for this command and exit
+    #[arg(long, default_value_t = false, exclusive = true)]
+    pub examples: bool,
+}
+
+#[derive(Args)]
+pub struct Transfer {
+    /// Repository to transfer (name or owner/name)
+    #[arg(required_unless_present = "examples")]
+    pub name: Option<String>,
+
+    /// User or organization that will own the repository
+    #[arg(required_unless_present = "examples")]
+    pub new_owner: Option<String>,
+
+    /// ID of a team in the new organization to grant access; repeat for several
+    #[arg(long = "team-id")]
+    pub team_ids: Vec<u64>,
+
+    /// Print usage examples for this command and exit
+    #[arg(long, default_value_t = false, exclusive = true)]
+    pub examples: bool,
+}
+
+#[derive(Args)]
+pub struct List {
+    /// Only show repositories created on or after this date (YYYY-MM-DD)
+    #[arg(long)]
+    pub since_created: Option<String>,
+
+    /// Only show repositories created on or before this date (YYYY-MM-DD)
+    #[arg(long)]
+    pub until: Option<String>,
+
+    /// Only show private repositories
+    #[arg(long, default_value_t = false, conflicts_with = "public")]
+    pub private: bool,
+
+    /// Only show public repositories
+    #[arg(long, default_value_t = false)]
+    pub public: bool,
+
+    /// Show at most this many repositories
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..))]
+    pub limit: Option<u32>,
+
+    /// Print stable tab-separated lines for scripts instead of the table
+    #[arg(long, default_value_t = false)]
+    pub porcelain: bool,
+
+    /// Print usage examples for this command and exit
+    #[arg(long, default_value_t = false, exclusive = true)]
+    pub examples: bool,
 }
@@ -0,0 +1,155 @@
+use crate::cli;
+use crate::outln;
+use crate::utils::config::Config;
+use crate::utils::github;
+use crate::utils::http::{self, ClientOptions, SendLogged};
+use crate::utils::output::Example;
+use crate::utils::prompt::{self, Assumed};
+use anyhow::{Context, Result, anyhow};
+use console::{Style, style};
+use dialoguer::theme::ColorfulTheme;
+use reqwest::blocking::Client;
+use serde::{Deserialize, Serialize};
+
+/// Invocations printed by `repogen archive --examples`
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        description: "Archive a finished project",
+        command: "repogen archive old-project",
+    },
+    Example {
+        description: "Make an archived repository writable again",
+        command: "repogen archive old-project --unarchive",
+    },
+    Example {
+        description: "Archive a repository in an organization without being asked",
+        command: "repogen --yes archive acme/legacy-service",
+    },
+];
+
+/// Handles archiving and unarchiving repositories
+pub struct ArchiveHandler {
+    config: Config,
+    args: cli::Archive,
+    name: String,
+    api_version: String,
+    token: Option<String>,
+    assume_yes: bool,
+    client: Client,
+}
+
+/// Request body that changes a repository's archive status
+#[derive(Debug, Serialize)]
+struct ArchiveRequest {
+    archived: bool,
+}
+
+/// The repository state GitHub reports after the change
+#[derive(Debug, Deserialize)]
+struct ArchiveResponse {
+    full_name: String,
+    html_url: String,
+    archived: bool,
+}
+
+impl ArchiveHandler {
+    /// Create a new ArchiveHandler instance
+    pub fn new(args: cli::Archive, global: &cli::GlobalArgs) -> Result<Self> {
+        let config = Config::load_or_recover().context("Failed to load configuration")?;
+        config.print_warnings();
+        let api_version = global
+            .api_version
+            .clone()
+            .unwrap_or_else(|| config.api_version().to_string());
+        let token = config.resolve_token(global.token_file.as_deref())?;
+        let client = http::client(&ClientOptions::resolve(global, &config))?;
+
+        let name = args.name.clone().context("No repository given")?;
+
+        Ok(Self {
+            config,
+            args,
+            name,
+            api_version,
+            token,
+            assume_yes: global.assume_yes,
+            client,
+        })
+    }
+
+    /// Archive or unarchive the repository, unless it's already in that state
+    pub fn run(&self) -> Result<()> {
+        let token = self.token.as_ref().ok_or_else(|| {
+            anyhow!("No GitHub token found. Run `repogen init --auth` to authenticate.")
+        })?;
+        let (owner, repo) = github::split_repo(&self.name, self.config.github_username.as_deref())?;
+        let archive = !self.args.unarchive;
+
+        let existing = github::find_repo(&self.client, token, &self.api_version, &owner, &repo)?
+            .ok_or_else(|| anyhow!("Repository {}/{} not found", owner, repo))?;
+
+        if existing.archived == archive {
+            outln!(
+                "\nℹ️  {} is already {}, nothing to do.",
+                existing.full_name,
+                Self::state(archive)
+            );
+            return Ok(());
+        }
+
+        if archive {
+            outln!(
+                "\n{}",
+                style(format!("📦 Archive {}", existing.full_name))
+                    .cyan()
+                    .bold()
+            );
+
+            let confirmed = prompt::confirm(
+                &ColorfulTheme::default(),
+                self.assume_yes,
+                &format!(
+                    "Archive {}? It becomes read-only: no pushes, issues or pull requests",
+                    existing.full_name
+                ),
+                false,
+                Assumed::Yes,
+            );
+            if !confirmed {
+                outln!("❌ Archive cancelled.");
+                return Ok(());
+            }
+        }
+
+        let url = format!("{}/repos/{}/{}", github::api_base(), owner, repo);
+        let response = github::with_headers(self.client.patch(&url), token, &self.api_version)
+            .json(&ArchiveRequest { archived: archive })
+            .send_logged()
+            .context("Failed to send request to GitHub API")?;
+
+        if !response.status().is_success() {
+            return Err(github::api_error(response));
+        }
+
+        let updated: ArchiveResponse = response
+            .json()
+            .context("Failed to parse GitHub API response")?;
+
+        outln!(
+            "\n{}",
+            Style::new().green().bold().apply_to(format!(
+                "✅ {} is now {}",
+                updated.full_name,
+                Self::state(updated.archived)
+            ))
+        );
+        outln!("   URL: {}", updated.html_url);
+
+        Ok(())
+    }
+
+    /// How an archive status reads in messages
+    fn state(archived: bool) -> &'static str {
+        if archived { "archived" } else { "unarchived" }
+    }
+}
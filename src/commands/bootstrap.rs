@@ -0,0 +1,275 @@
+use crate::cli;
+use crate::commands::NewHandler;
+use crate::outln;
+use crate::utils::config::{Config, GITIGNORE_OPTIONS, LICENSE_OPTIONS};
+use crate::utils::github;
+use crate::utils::gitignore;
+use crate::utils::output::Example;
+use crate::utils::prompt::{self, Assumed};
+use anyhow::{Context, Result, anyhow};
+use console::style;
+use dialoguer::{Input, MultiSelect, Select, theme::ColorfulTheme};
+use std::env;
+use std::path::PathBuf;
+
+/// Invocations printed by `repogen bootstrap --examples`
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        description: "Answer a few questions and create the project",
+        command: "repogen bootstrap",
+    },
+    Example {
+        description: "Detect languages from another directory",
+        command: "repogen bootstrap --path ~/code/myapp",
+    },
+];
+
+/// Walks through creating a new project one question at a time
+pub struct BootstrapHandler {
+    config: Config,
+    args: cli::Bootstrap,
+    global: cli::GlobalArgs,
+    theme: ColorfulTheme,
+}
+
+/// Answers collected by the wizard
+struct Answers {
+    name: String,
+    description: Option<String>,
+    private: bool,
+    languages: Vec<String>,
+    license: Option<String>,
+    readme: bool,
+    topics: Vec<String>,
+    clone: bool,
+}
+
+impl BootstrapHandler {
+    /// Create a new BootstrapHandler instance
+    pub fn new(args: cli::Bootstrap, global: &cli::GlobalArgs) -> Result<Self> {
+        let config = Config::load_or_recover().context("Failed to load configuration")?;
+        config.print_warnings();
+
+        Ok(Self {
+            config,
+            args,
+            global: global.clone(),
+            theme: ColorfulTheme::default(),
+        })
+    }
+
+    /// Ask about the project, then create it with the answers
+    pub fn run(&self) -> Result<()> {
+        if !console::user_attended() {
+            return Err(anyhow!(
+                "The bootstrap wizard needs an interactive terminal. Use `repogen new` in scripts."
+            ));
+        }
+
+        outln!("\n🚀 Let's set up a new project\n");
+
+        let answers = self.ask();
+        self.display_summary(&answers);
+
+        if !prompt::confirm(
+            &self.theme,
+            self.global.assume_yes,
+            "Create this repository?",
+            true,
+            Assumed::Yes,
+        ) {
+            outln!("\nCancelled, nothing was created.");
+            return Ok(());
+        }
+
+        let mut handler = NewHandler::new(Self::new_args(answers), &self.global)?;
+        handler.skip_confirmation();
+        handler.create_repository()
+    }
+
+    /// Ask every wizard question in turn
+    fn ask(&self) -> Answers {
+        let name: String = Input::with_theme(&self.theme)
+            .with_prompt("Repository name")
+            .validate_with(|input: &String| github::validate_repo_name(input))
+            .interact_text()
+            .unwrap();
+
+        let description: String = Input::with_theme(&self.theme)
+            .with_prompt("Description (optional)")
+            .allow_empty(true)
+            .interact_text()
+            .unwrap();
+
+        let visibility = Select::with_theme(&self.theme)
+            .with_prompt("Visibility")
+            .items(["Public", "Private"])
+            .default(usize::from(self.config.default_private))
+            .interact()
+            .unwrap();
+
+        let languages = self.ask_languages();
+
+        let license = Select::with_theme(&self.theme)
+            .with_prompt("License")
+            .items(LICENSE_OPTIONS)
+            .default(self.default_license_index())
+            .interact()
+            .unwrap();
+
+        let readme = prompt::confirm(
+            &self.theme,
+            false,
+            "Initialize with a README?",
+            true,
+            Assumed::Default,
+        );
+
+        let topics = self.ask_topics();
+
+        let clone = prompt::confirm(
+            &self.theme,
+            false,
+            "Clone the repository when it's created?",
+            self.config.auto_clone,
+            Assumed::Default,
+        );
+
+        Answers {
+            name,
+            description: Some(description.trim().to_string()).filter(|d| !d.is_empty()),
+            private: visibility == 1,
+            languages,
+            license: Some(LICENSE_OPTIONS[license].to_string()).filter(|_| license != 0),
+            readme,
+            topics,
+            clone,
+        }
+    }
+
+    /// Offer .gitignore languages, pre-selecting the ones detected in the project directory
+    fn ask_languages(&self) -> Vec<String> {
+        let dir = match &self.args.path {
+            Some(path) => path.clone(),
+            None => env::current_dir().unwrap_or_else(|_| PathBuf::from(".")),
+        };
+        let detected = gitignore::detect_templates(&dir);
+
+        let mut options: Vec<&str> = detected.clone();
+        for option in &GITIGNORE_OPTIONS[1..] {
+            if !options.contains(option) {
+                options.push(option);
+            }
+        }
+        let defaults: Vec<bool> = options
+            .iter()
+            .map(|option| detected.contains(option))
+            .collect();
+
+        let selected = MultiSelect::with_theme(&self.theme)
+            .with_prompt("Languages for .gitignore (space to toggle, enter to confirm)")
+            .items(&options)
+            .defaults(&defaults)
+            .interact()
+            .unwrap();
+
+        selected
+            .into_iter()
+            .map(|index| options[index].to_string())
+            .collect()
+    }
+
+    /// Ask for comma-separated topics until they pass GitHub's rules
+    fn ask_topics(&self) -> Vec<String> {
+        let input: String = Input::with_theme(&self.theme)
+            .with_prompt("Topics, comma-separated (optional)")
+            .allow_empty(true)
+            .validate_with(|input: &String| {
+                github::validate_topics(&Self::split_topics(input)).map_err(|e| e.to_string())
+            })
+            .interact_text()
+            .unwrap();
+
+        Self::split_topics(&input)
+    }
+
+    /// Split a comma-separated topic list, dropping empty entries
+    fn split_topics(input: &str) -> Vec<String> {
+        input
+            .split(',')
+            .map(|topic| topic.trim().to_lowercase())
+            .filter(|topic| !topic.is_empty())
+            .collect()
+    }
+
+    /// Position of the configured default license in the options list
+    fn default_license_index(&self) -> usize {
+        self.config
+            .default_license
+            .as_deref()
+            .and_then(|license| LICENSE_OPTIONS.iter().position(|option| *option == license))
+            .unwrap_or(0)
+    }
+
+    /// Show what's about to be created
+    fn display_summary(&self, answers: &Answers) {
+        let none = || style("none").dim().to_string();
+
+        outln!("\n📋 Summary:");
+        outln!("   Name: {}", answers.name);
+        outln!(
+            "   Description: {}",
+            answers.description.clone().unwrap_or_else(none)
+        );
+        outln!(
+            "   Visibility: {}",
+            if answers.private { "Private" } else { "Public" }
+        );
+        outln!(
+            "   .gitignore: {}",
+            if answers.languages.is_empty() {
+                none()
+            } else {
+                answers.languages.join(" + ")
+            }
+        );
+        outln!(
+            "   License: {}",
+            answers.license.clone().unwrap_or_else(none)
+        );
+        outln!("   README: {}", if answers.readme { "Yes" } else { "No" });
+        outln!(
+            "   Topics: {}",
+            if answers.topics.is_empty() {
+                none()
+            } else {
+                answers.topics.join(", ")
+            }
+        );
+        outln!("   Clone: {}\n", if answers.clone { "Yes" } else { "No" });
+    }
+
+    /// Turn the answers into the arguments `repogen new` would have received
+    fn new_args(answers: Answers) -> cli::New {
+        // One language uses GitHub's template directly; several are combined
+        let (gitignore, gitignore_from_languages) = match answers.languages.len() {
+            0 => (Some("None".to_string()), None),
+            1 => (answers.languages.into_iter().next(), None),
+            _ => (None, Some(answers.languages)),
+        };
+
+        cli::New {
+            name: Some(answers.name),
+            description: answers.description,
+            visibility: Some(if answers.private { "private" } else { "public" }.to_string()),
+            license: Some(answers.license.unwrap_or_else(|| "None".to_string())),
+            gitignore,
+            gitignore_from_languages,
+            readme: answers.readme,
+            topic: answers.topics,
+            clone: answers.clone,
+            no_clone: !answers.clone,
+            ..Default::default()
+        }
+    }
+}
@@ -0,0 +1,354 @@
+use crate::cli;
+use crate::utils::config::Config;
+use crate::utils::http::{self, ClientOptions};
+use crate::utils::output::{self, Example};
+use crate::utils::timing::Timer;
+use crate::utils::{editor, git, github, last_repo, symbols};
+use crate::{errln, outln};
+use anyhow::{Context, Result, anyhow};
+use console::Style;
+use indicatif::{ProgressBar, ProgressStyle};
+use reqwest::blocking::Client;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::Duration;
+
+/// Invocations printed by `repogen clone --examples`
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        description: "Clone one of your repositories",
+        command: "repogen clone my-project",
+    },
+    Example {
+        description: "Clone someone else's repository over SSH and open it in your editor",
+        command: "repogen clone octocat/hello-world --ssh --open",
+    },
+    Example {
+        description: "Clone the repository you just created with `repogen new`",
+        command: "repogen clone",
+    },
+    Example {
+        description: "Clone every repository you own",
+        command: "repogen clone --all",
+    },
+    Example {
+        description: "Clone an organization's repositories, eight at a time",
+        command: "repogen clone --all --org acme --jobs 8",
+    },
+    Example {
+        description: "Clone only your private repositories",
+        command: "repogen clone --all --private-only",
+    },
+];
+
+/// Handles cloning existing repositories
+pub struct CloneHandler {
+    config: Config,
+    args: cli::Clone,
+    api_version: String,
+    token: Option<String>,
+    client: Client,
+}
+
+/// Result of cloning a single repository during `clone --all`
+enum CloneOutcome {
+    Cloned,
+    Skipped,
+    Failed,
+}
+
+impl CloneHandler {
+    /// Create a new CloneHandler instance
+    pub fn new(args: cli::Clone, global: &cli::GlobalArgs) -> Result<Self> {
+        let config = Config::load_or_recover().context("Failed to load configuration")?;
+        config.print_warnings();
+        let api_version = global
+            .api_version
+            .clone()
+            .unwrap_or_else(|| config.api_version().to_string());
+        let token = config.resolve_token(global.token_file.as_deref())?;
+        let client = http::client(&ClientOptions::resolve(global, &config))?;
+
+        Ok(Self {
+            config,
+            args,
+            api_version,
+            token,
+            client,
+        })
+    }
+
+    /// Run the clone command
+    pub fn run(&self) -> Result<()> {
+        if self.args.all {
+            return self.clone_all();
+        }
+
+        match &self.args.name {
+            Some(name) => self.clone_one(name),
+            None => match last_repo::load()? {
+                Some(last) => {
+                    outln!(
+                        "📌 Cloning {}, the last repository you created",
+                        last.full_name
+                    );
+                    self.clone_one(&last.full_name)
+                }
+                None => Err(anyhow!(
+                    "Nothing to clone. Pass a repository name, or --all to clone all of your repositories."
+                )),
+            },
+        }
+    }
+
+    /// Clone a single repository, given as `name` or `owner/name`
+    fn clone_one(&self, name: &str) -> Result<()> {
+        let token = self.token.as_ref().ok_or_else(|| {
+            anyhow!("No GitHub token found. Run `repogen init --auth` to authenticate.")
+        })?;
+
+        let (owner, repo_name) = github::split_repo(name, self.config.github_username.as_deref())?;
+        let repo = github::find_repo(&self.client, token, &self.api_version, &owner, &repo_name)?
+            .ok_or_else(|| anyhow!("Repository {}/{} not found", owner, repo_name))?;
+
+        let repo_path = clone_repository(
+            &self.config,
+            self.url(&repo),
+            &repo.name,
+            self.config.clone_recurse_submodules,
+        )?;
+
+        let (name, email) = self.config.local_git_identity();
+        set_identity(&repo_path, name, email);
+
+        if self.args.open {
+            match &self.config.preferred_editor {
+                Some(preferred) => {
+                    if let Err(e) = editor::open(preferred, &repo_path) {
+                        errln!("⚠️  Could not open the repository: {}", e);
+                    }
+                }
+                None => outln!(
+                    "💡 No preferred editor configured. Run `repogen init --meta` to pick one."
+                ),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The URL to clone `repo` from: SSH or HTTPS, as configured
+    fn url<'a>(&self, repo: &'a github::RepoSummary) -> &'a str {
+        if self.args.ssh || self.config.clone_over_ssh() {
+            &repo.ssh_url
+        } else {
+            &repo.clone_url
+        }
+    }
+
+    /// Clone every repository of the user (or organization) into the clone directory
+    fn clone_all(&self) -> Result<()> {
+        let timer = Timer::start();
+        let cyan = Style::new().cyan().bold();
+        let green = Style::new().green().bold();
+        let dim = Style::new().dim();
+
+        let token = self.token.as_ref().ok_or_else(|| {
+            anyhow!("No GitHub token found. Run `repogen init --auth` to authenticate.")
+        })?;
+
+        let owner = self
+            .args
+            .org
+            .clone()
+            .or_else(|| self.config.github_username.clone())
+            .unwrap_or_else(|| "your account".to_string());
+
+        outln!(
+            "\n{}",
+            cyan.apply_to(format!("📋 Fetching repositories for {}...", owner))
+        );
+
+        let client = &self.client;
+        let repos: Vec<github::RepoSummary> =
+            github::list_repos(client, token, &self.api_version, self.args.org.as_deref())?
+                .into_iter()
+                .filter(|repo| {
+                    if self.args.private_only {
+                        repo.private
+                    } else if self.args.public_only {
+                        !repo.private
+                    } else {
+                        true
+                    }
+                })
+                .collect();
+
+        if repos.is_empty() {
+            outln!("ℹ️  No repositories matched.");
+            return Ok(());
+        }
+
+        let target_dir = git::clone_root(&self.config)?;
+        outln!(
+            "{}",
+            cyan.apply_to(format!(
+                "📥 Cloning {} repositories into {}",
+                repos.len(),
+                target_dir.display()
+            ))
+        );
+
+        let (identity_name, identity_email) = self.config.local_git_identity();
+        let identity = [("user.name", identity_name), ("user.email", identity_email)];
+        let shown: Vec<String> = identity_name
+            .map(str::to_string)
+            .into_iter()
+            .chain(identity_email.map(|email| format!("<{}>", email)))
+            .collect();
+        if !shown.is_empty() {
+            outln!(
+                "👤 Setting the local git identity of each clone to {}",
+                shown.join(" ")
+            );
+        }
+
+        // Workers pull the next repository index until the list is exhausted
+        let next = AtomicUsize::new(0);
+        let outcomes = Mutex::new(Vec::with_capacity(repos.len()));
+
+        thread::scope(|scope| {
+            for _ in 0..self.args.jobs {
+                scope.spawn(|| {
+                    while let Some(repo) = repos.get(next.fetch_add(1, Ordering::SeqCst)) {
+                        let outcome = if target_dir.join(&repo.name).exists() {
+                            outln!("   {} {}", dim.apply_to("⏭️ "), repo.name);
+                            CloneOutcome::Skipped
+                        } else {
+                            match git::clone(
+                                self.url(repo),
+                                &target_dir,
+                                self.config.clone_recurse_submodules,
+                            ) {
+                                Ok(()) => {
+                                    let repo_path = target_dir.join(&repo.name);
+                                    for (key, value) in identity {
+                                        if let Some(value) = value
+                                            && let Err(e) = git::set_config(&repo_path, key, value)
+                                        {
+                                            errln!(
+                                                "   ⚠️  {}: could not set {}: {}",
+                                                repo.name,
+                                                key,
+                                                e
+                                            );
+                                        }
+                                    }
+                                    outln!("   {} {}", green.apply_to("✅"), repo.name);
+                                    CloneOutcome::Cloned
+                                }
+                                Err(e) => {
+                                    errln!("   ❌ {}: {}", repo.name, e);
+                                    CloneOutcome::Failed
+                                }
+                            }
+                        };
+
+                        outcomes.lock().unwrap().push(outcome);
+                    }
+                });
+            }
+        });
+
+        let outcomes = outcomes.into_inner().unwrap();
+        let count = |f: fn(&CloneOutcome) -> bool| outcomes.iter().filter(|o| f(o)).count();
+        let cloned = count(|o| matches!(o, CloneOutcome::Cloned));
+        let skipped = count(|o| matches!(o, CloneOutcome::Skipped));
+        let failed = count(|o| matches!(o, CloneOutcome::Failed));
+
+        outln!(
+            "\n{} Cloned {} repos in {} ({} already present, {} failed)",
+            green.apply_to("📊"),
+            cloned,
+            timer.elapsed(),
+            skipped,
+            failed
+        );
+
+        if failed > 0 {
+            return Err(anyhow!("{} repositories failed to clone", failed));
+        }
+
+        Ok(())
+    }
+}
+
+/// Set `user.name` and `user.email` in a clone's own git config, reporting each
+pub fn set_identity(repo_path: &Path, name: Option<&str>, email: Option<&str>) {
+    for (key, value) in [("user.name", name), ("user.email", email)] {
+        let Some(value) = value else {
+            continue;
+        };
+
+        match git::set_config(repo_path, key, value) {
+            Ok(()) => outln!("👤 Set {} to {}", key, value),
+            Err(e) => errln!("⚠️  Could not set {}: {}", key, e),
+        }
+    }
+}
+
+/// Clone `url` into the configured clone directory and say where it went
+///
+/// Returns the path of the new checkout, `name` being the repository's name.
+pub fn clone_repository(
+    config: &Config,
+    url: &str,
+    name: &str,
+    recurse_submodules: bool,
+) -> Result<PathBuf> {
+    let cyan = Style::new().cyan().bold();
+    let green = Style::new().green().bold();
+
+    outln!("\n{}", cyan.apply_to("📥 Cloning repository..."));
+
+    let target_dir = git::clone_root(config)?;
+    let repo_path = target_dir.join(name);
+    if repo_path.exists() {
+        return Err(anyhow!("{} already exists", repo_path.display()));
+    }
+
+    // git's own progress is relayed next to a spinner so large clones don't look stuck
+    let pb = if output::is_quiet() {
+        ProgressBar::hidden()
+    } else {
+        ProgressBar::new_spinner()
+    };
+    pb.set_style(
+        ProgressStyle::default_spinner()
+            .tick_chars(symbols::spinner_ticks())
+            .template("{spinner:.cyan} {msg}")
+            .unwrap(),
+    );
+    pb.set_message("Connecting...");
+    pb.enable_steady_tick(Duration::from_millis(120));
+
+    let cloned = git::clone_with_progress(url, &target_dir, recurse_submodules, |line| {
+        pb.set_message(line.to_string())
+    });
+    pb.finish_and_clear();
+    cloned?;
+
+    let repo_path_str = repo_path.display().to_string();
+    outln!(
+        "{} Repository cloned to: {}",
+        green.apply_to("✅"),
+        cyan.apply_to(&repo_path_str)
+    );
+
+    outln!("\n{}", cyan.apply_to("💡 Navigate to your repository:"));
+    outln!("   cd {}", repo_path_str);
+
+    Ok(repo_path)
+}
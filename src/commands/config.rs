@@ -1,91 +1,477 @@
-use crate::utils::config::Config;
+use crate::cli;
+use crate::utils::config::{
+    self, Config, DEFAULT_REQUIRED_REVIEWS, EDITOR_OPTIONS, GITATTRIBUTES_OPTIONS, LICENSE_OPTIONS,
+    Override, SYMBOL_SET_OPTIONS, TOKEN_FILE_ENV,
+};
+use crate::utils::gitignore;
+use crate::utils::output::Example;
+use crate::utils::prompt::{self, Assumed};
+use crate::utils::timing::format_duration;
+use crate::{errln, outln};
+use anyhow::anyhow;
 use console::style;
-use dialoguer::{Confirm, Input, Select, theme::ColorfulTheme};
+use dialoguer::{Input, MultiSelect, Select, theme::ColorfulTheme};
+use std::path::Path;
+use std::process;
+
+/// Invocations printed by `repogen config --examples`
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        description: "Show the saved configuration",
+        command: "repogen config",
+    },
+    Example {
+        description: "Show the values that apply once environment variables are merged in",
+        command: "repogen config --merge-env",
+    },
+    Example {
+        description: "Export settings into the current shell",
+        command: "eval \"$(repogen config --env)\"",
+    },
+    Example {
+        description: "Change settings from a script",
+        command: "repogen config --set default_license=MIT --set auto_clone=true",
+    },
+    Example {
+        description: "Share your setup without its secrets",
+        command: "repogen config --export repogen.toml",
+    },
+    Example {
+        description: "Change settings interactively",
+        command: "repogen config --edit",
+    },
+    Example {
+        description: "Reset only some sections",
+        command: "repogen config --clear --interactive",
+    },
+    Example {
+        description: "Find and repair problems",
+        command: "repogen config --doctor-fix",
+    },
+    Example {
+        description: "Protect the configuration from changes",
+        command: "repogen config --lock",
+    },
+];
 
 /// Handles configuration management for repogen
 pub struct ConfigHandler {
     config: Config,
     theme: ColorfulTheme,
+    assume_yes: bool,
+    overrides: Vec<Override>,
 }
 
 impl ConfigHandler {
     /// Create a new ConfigHandler instance
-    pub fn new() -> Self {
-        let config = Config::load().unwrap_or_else(|e| {
-            eprintln!("Warning: Could not load config: {}", e);
+    pub fn new(global: &cli::GlobalArgs) -> Self {
+        let config = Config::load_or_recover().unwrap_or_else(|e| {
+            errln!("Warning: Could not load config: {}", e);
             Config::default()
         });
+        config.print_warnings();
 
         Self {
             config,
             theme: ColorfulTheme::default(),
+            assume_yes: global.assume_yes,
+            overrides: Vec::new(),
         }
     }
 
     /// Entry point for viewing configuration
-    pub fn handle_view() {
-        let handler = Self::new();
+    ///
+    /// With `merge_env`, shows the effective values after environment and
+    /// flag overrides instead of the raw file contents.
+    pub fn handle_view(global: &cli::GlobalArgs, merge_env: bool) {
+        let mut handler = Self::new(global);
+
+        if merge_env {
+            match handler.config.apply_overrides(global) {
+                Ok(overrides) => handler.overrides = overrides,
+                Err(e) => errln!("⚠️  Could not apply overrides: {}", e),
+            }
+        }
+
         handler.display_config();
+
+        if !merge_env && Self::has_pending_overrides(global) {
+            outln!(
+                "💡 Overrides are active; run {} to see effective values\n",
+                style("repogen config --view --merge-env").cyan()
+            );
+        }
     }
 
     /// Entry point for editing configuration
-    pub fn handle_edit() {
-        let mut handler = Self::new();
+    pub fn handle_edit(global: &cli::GlobalArgs, force: bool) {
+        let mut handler = Self::new(global);
+        Self::exit_if_locked(&handler.config, force);
         handler.edit_config();
     }
 
     /// Entry point for clearing configuration
-    pub fn handle_clear() {
-        Self::clear_config();
+    pub fn handle_clear(global: &cli::GlobalArgs, force: bool) {
+        if let Ok(config) = Config::load() {
+            Self::exit_if_locked(&config, force);
+        }
+        Self::clear_config(global.assume_yes);
+    }
+
+    /// Entry point for clearing selected configuration sections
+    pub fn handle_clear_sections(global: &cli::GlobalArgs, force: bool) {
+        let mut handler = Self::new(global);
+        Self::exit_if_locked(&handler.config, force);
+        handler.clear_sections();
+    }
+
+    /// Entry point for repairing configuration
+    pub fn handle_doctor_fix(global: &cli::GlobalArgs, force: bool) {
+        let mut handler = Self::new(global);
+        Self::exit_if_locked(&handler.config, force);
+        handler.doctor_fix();
+    }
+
+    /// Entry point for `--lock` and `--unlock`
+    pub fn handle_lock(global: &cli::GlobalArgs, locked: bool) {
+        let mut handler = Self::new(global);
+
+        if handler.config.locked == locked {
+            outln!(
+                "ℹ️  Configuration is already {}.",
+                if locked { "locked" } else { "unlocked" }
+            );
+            return;
+        }
+
+        handler.config.locked = locked;
+        if let Err(e) = handler.config.save() {
+            errln!("❌ Failed to save config: {}", e);
+            process::exit(1);
+        }
+
+        if locked {
+            outln!("🔒 Configuration locked.");
+            outln!(
+                "💡 Run {} to allow changes again",
+                style("repogen config --unlock").cyan()
+            );
+        } else {
+            outln!("✅ Configuration unlocked.");
+        }
+    }
+
+    /// Entry point for `--export <path>`
+    pub fn handle_export(global: &cli::GlobalArgs, path: &Path, include_secrets: bool) {
+        let handler = Self::new(global);
+
+        if let Err(e) = handler.config.export(path, include_secrets) {
+            errln!("❌ Failed to export config: {}", e);
+            process::exit(1);
+        }
+
+        outln!("✅ Configuration exported to {}", path.display());
+        if include_secrets {
+            errln!("⚠️  The file includes your GitHub token and other secrets. Keep it private.");
+        } else {
+            outln!(
+                "💡 Tokens, the OAuth client ID and the webhook URL were left out; pass {} to keep them",
+                style("--include-secrets").cyan()
+            );
+        }
+    }
+
+    /// Entry point for `--set key=value`
+    ///
+    /// Every pair is checked before anything is saved, so one bad pair leaves
+    /// the config untouched.
+    pub fn handle_set(global: &cli::GlobalArgs, pairs: &[String], force: bool) {
+        let mut handler = Self::new(global);
+        Self::exit_if_locked(&handler.config, force);
+
+        for pair in pairs {
+            let result = match pair.split_once('=') {
+                Some((key, value)) => handler.config.set_value(key.trim(), value),
+                None => Err(anyhow!("Expected key=value, got '{}'", pair)),
+            };
+            if let Err(e) = result {
+                errln!("❌ {}", e);
+                process::exit(1);
+            }
+        }
+
+        if let Err(e) = handler.config.save() {
+            errln!("❌ Failed to save config: {}", e);
+            process::exit(1);
+        }
+
+        for pair in pairs {
+            outln!("✅ Set {}", pair.trim());
+        }
+        for warning in handler.config.validate() {
+            errln!("⚠️  Config: {}", warning);
+        }
+    }
+
+    /// Entry point for `--env`: shell `export` statements on stdout
+    ///
+    /// The token is left out unless `include_token` is set, since the output
+    /// often ends up in logs.
+    pub fn handle_env(global: &cli::GlobalArgs, include_token: bool) {
+        let handler = Self::new(global);
+        let config = &handler.config;
+
+        let variables = [
+            ("REPOGEN_PROFILE", Some(config::active_profile())),
+            ("REPOGEN_USERNAME", config.github_username.as_deref()),
+            ("REPOGEN_HOST", Some(config.github_host())),
+            ("REPOGEN_API_VERSION", Some(config.api_version())),
+        ];
+        for (name, value) in variables {
+            if let Some(value) = value {
+                println!("export {}={}", name, Self::shell_quote(value));
+            }
+        }
+
+        if include_token {
+            match config.resolve_token(global.token_file.as_deref()) {
+                Ok(Some(token)) => {
+                    errln!(
+                        "⚠️  Printing your GitHub token. Don't share this output or leave it in logs."
+                    );
+                    println!("export REPOGEN_TOKEN={}", Self::shell_quote(&token));
+                }
+                Ok(None) => errln!("⚠️  No GitHub token found, REPOGEN_TOKEN not exported"),
+                Err(e) => {
+                    errln!("❌ {}", e);
+                    process::exit(1);
+                }
+            }
+        }
+    }
+
+    /// Quote a value for POSIX shells
+    fn shell_quote(value: &str) -> String {
+        format!("'{}'", value.replace('\'', "'\\''"))
+    }
+
+    /// Stop a mutating command when the config is locked and not forced
+    fn exit_if_locked(config: &Config, force: bool) {
+        if let Err(e) = config.ensure_unlocked(force) {
+            errln!("❌ {}", e);
+            process::exit(1);
+        }
     }
 
     /// Display current configuration in a nice format
     fn display_config(&self) {
-        println!("\n{}", style("📋 repogen Configuration").cyan().bold());
-        println!("{}", style("━".repeat(50)).dim());
+        outln!("\n{}", style("📋 repogen Configuration").cyan().bold());
+        outln!("{}", style("━".repeat(50)).dim());
 
         // User Profile Section
-        println!("\n{}", style("👤 User Profile").green().bold());
+        outln!("\n{}", style("👤 User Profile").green().bold());
         self.print_field("GitHub Username", &self.config.github_username);
         self.print_field("Full Name", &self.config.user_name);
         self.print_field("Email", &self.config.user_email);
 
         // Authentication Section
-        println!("\n{}", style("🔐 Authentication").green().bold());
-        if let Some(ref token) = self.config.github_token {
+        outln!("\n{}", style("🔐 Authentication").green().bold());
+        outln!(
+            "  GitHub Host: {}",
+            style(self.config.github_host()).yellow()
+        );
+        outln!(
+            "  API URL: {}{}",
+            style(self.config.api_base_url()).yellow(),
+            self.source_marker("api_base_url")
+        );
+        if self.config.has_dry_run_token() {
+            outln!(
+                "  GitHub Token: {}",
+                style("Dry-run placeholder (not usable)").red()
+            );
+        } else if let Some(token) = self.config.token() {
             let masked = format!("{}***", &token[..std::cmp::min(8, token.len())]);
-            println!("  GitHub Token: {}", style(masked).yellow());
+            outln!(
+                "  GitHub Token: {}{}",
+                style(masked).yellow(),
+                self.source_marker("github_token")
+            );
         } else {
-            println!("  GitHub Token: {}", style("Not configured").red());
+            outln!("  GitHub Token: {}", style("Not configured").red());
+        }
+        let other_hosts: Vec<&str> = self
+            .config
+            .github_tokens
+            .keys()
+            .map(String::as_str)
+            .filter(|host| *host != self.config.github_host())
+            .collect();
+        if !other_hosts.is_empty() {
+            outln!(
+                "  Tokens also stored for: {}",
+                style(other_hosts.join(", ")).dim()
+            );
         }
 
         // Repository Defaults Section
-        println!("\n{}", style("⚙️  Repository Defaults").green().bold());
-        println!(
+        outln!("\n{}", style("⚙️  Repository Defaults").green().bold());
+        outln!(
             "  Private by default: {}",
             self.format_bool(self.config.default_private)
         );
         self.print_field("Default License", &self.config.default_license);
         self.print_field("Default .gitignore", &self.config.default_gitignore);
+        self.print_field("Default .gitattributes", &self.config.default_gitattributes);
         self.print_field("Preferred Editor", &self.config.preferred_editor);
+        outln!(
+            "  Open in browser: {}",
+            self.format_bool(self.config.auto_open)
+        );
+        outln!(
+            "  Sanitize descriptions: {}",
+            self.format_bool(self.config.sanitize_description)
+        );
+        self.print_field("Commit Message", &self.config.default_commit_message);
+        self.print_field("Description Template", &self.config.description_template);
+        if !self.config.description_templates.is_empty() {
+            let languages: Vec<&str> = self
+                .config
+                .description_templates
+                .keys()
+                .map(String::as_str)
+                .collect();
+            outln!(
+                "  Description Languages: {}",
+                style(languages.join(", ")).yellow()
+            );
+        }
+        self.print_field("Default Branch", &self.config.default_branch);
+        if !self.config.default_labels.is_empty() {
+            let labels: Vec<&str> = self
+                .config
+                .default_labels
+                .iter()
+                .map(|label| label.name.as_str())
+                .collect();
+            outln!("  Default Labels: {}", style(labels.join(", ")).yellow());
+        }
+        outln!(
+            "  Secret scanning: {}",
+            self.format_bool(self.config.enable_secret_scanning)
+        );
+        outln!(
+            "  Push protection: {}",
+            self.format_bool(self.config.enable_push_protection)
+        );
+        outln!(
+            "  Required reviews: {}",
+            style(
+                self.config
+                    .required_reviews
+                    .unwrap_or(DEFAULT_REQUIRED_REVIEWS)
+            )
+            .yellow()
+        );
+        outln!(
+            "  Code owner reviews: {}",
+            self.format_bool(self.config.require_code_owner_reviews)
+        );
+        outln!(
+            "  Dismiss stale reviews: {}",
+            self.format_bool(self.config.dismiss_stale_reviews)
+        );
+        outln!(
+            "  Disable issues: {}",
+            self.format_bool(self.config.disable_issues)
+        );
+        outln!(
+            "  Disable wiki: {}",
+            self.format_bool(self.config.disable_wiki)
+        );
+        outln!(
+            "  Disable projects: {}",
+            self.format_bool(self.config.disable_projects)
+        );
 
         // Clone Settings Section
-        println!("\n{}", style("📁 Clone Settings").green().bold());
-        println!("  Auto-clone: {}", self.format_bool(self.config.auto_clone));
+        outln!("\n{}", style("📁 Clone Settings").green().bold());
+        outln!("  Auto-clone: {}", self.format_bool(self.config.auto_clone));
         self.print_field("Clone Directory", &self.config.clone_directory);
+        self.print_field("Clone Protocol", &self.config.clone_protocol);
+        self.print_field("Post-clone Command", &self.config.post_clone_command);
+        outln!(
+            "  Recurse submodules: {}",
+            self.format_bool(self.config.clone_recurse_submodules)
+        );
+        outln!(
+            "  Skip git config: {}",
+            self.format_bool(self.config.skip_git_config)
+        );
+        outln!(
+            "  Set local git identity: {}",
+            self.format_bool(self.config.set_local_git_identity)
+        );
+        outln!(
+            "  Commit signing: {}",
+            self.format_bool(self.config.commit_sign)
+        );
+        self.print_field("Signing Key", &self.config.signing_key);
+
+        // Notifications Section
+        outln!("\n{}", style("🔔 Notifications").green().bold());
+        self.print_field("Creation Webhook", &self.config.creation_webhook_url);
+
+        // GitHub API Section
+        outln!("\n{}", style("🌐 GitHub API").green().bold());
+        outln!(
+            "  API Version: {}{}",
+            style(self.config.api_version()).yellow(),
+            self.source_marker("github_api_version")
+        );
+        self.print_field("CA Bundle", &self.config.ca_bundle);
+        let policy = self.config.retry_policy(None, None);
+        outln!(
+            "  Retries: {} (first pause {})",
+            style(policy.max_retries).yellow(),
+            style(format_duration(policy.delay)).yellow()
+        );
+        outln!(
+            "  Request Timeout: {}",
+            style(format_duration(self.config.request_timeout())).yellow()
+        );
+
+        // Display Section
+        outln!("\n{}", style("🎨 Display").green().bold());
+        match &self.config.symbol_set {
+            Some(symbol_set) => outln!("  Symbol Set: {}", style(symbol_set).yellow()),
+            None => outln!("  Symbol Set: {}", style("auto-detect").dim()),
+        }
 
         // Config File Location
-        println!("\n{}", style("📁 Configuration File").green().bold());
+        outln!("\n{}", style("📁 Configuration File").green().bold());
         if let Ok(config_path) = Config::config_file() {
-            println!("  Location: {}", style(config_path.display()).cyan());
+            outln!("  Location: {}", style(config_path.display()).cyan());
+        }
+        if self.config.locked {
+            outln!(
+                "  {}",
+                style("🔒 Locked (run repogen config --unlock to allow changes)").yellow()
+            );
         }
 
-        println!("\n{}", style("━".repeat(50)).dim());
-        println!(
+        outln!("\n{}", style("━".repeat(50)).dim());
+        if !self.overrides.is_empty() {
+            outln!(
+                "\n{}",
+                style("Values marked (from ...) override the config file").dim()
+            );
+        }
+        outln!(
             "\n💡 Run {} to modify configuration",
             style("repogen config --edit").cyan()
         );
-        println!(
+        outln!(
             "💡 Run {} to reset configuration\n",
             style("repogen config --clear").cyan()
         );
@@ -94,12 +480,30 @@ impl ConfigHandler {
     /// Helper to print a configuration field
     fn print_field(&self, label: &str, value: &Option<String>) {
         if let Some(val) = value {
-            println!("  {}: {}", label, style(val).yellow());
+            outln!("  {}: {}", label, style(val).yellow());
         } else {
-            println!("  {}: {}", label, style("Not set").dim());
+            outln!("  {}: {}", label, style("Not set").dim());
         }
     }
 
+    /// Note where a value came from if it overrides the config file
+    fn source_marker(&self, field: &str) -> String {
+        self.overrides
+            .iter()
+            .find(|o| o.field == field)
+            .map(|o| style(format!(" (from {})", o.source)).dim().to_string())
+            .unwrap_or_default()
+    }
+
+    /// Whether any environment or flag override would change effective values
+    fn has_pending_overrides(global: &cli::GlobalArgs) -> bool {
+        global.token_file.is_some()
+            || global.api_version.is_some()
+            || global.api_url.is_some()
+            || std::env::var_os(TOKEN_FILE_ENV).is_some_and(|p| !p.is_empty())
+            || Config::env_token(|key| std::env::var_os(key)).is_some()
+    }
+
     /// Helper to format boolean values
     fn format_bool(&self, value: bool) -> String {
         if value {
@@ -111,13 +515,13 @@ impl ConfigHandler {
 
     /// Edit configuration interactively
     fn edit_config(&mut self) {
-        println!("\n{}", style("✏️  Edit Configuration").cyan().bold());
-        println!("Select what you'd like to edit:\n");
+        outln!("\n{}", style("✏️  Edit Configuration").cyan().bold());
+        outln!("Select what you'd like to edit:\n");
 
         let options = vec![
             "User Profile (username, name, email)",
             "Repository Defaults (privacy, license, gitignore, editor)",
-            "Clone Settings (auto-clone, directory)",
+            "Clone Settings (auto-clone, directory, commit signing)",
             "GitHub Authentication (token)",
             "Edit All",
             "Cancel",
@@ -137,7 +541,7 @@ impl ConfigHandler {
             3 => self.edit_authentication(),
             4 => self.edit_all(),
             5 => {
-                println!("❌ Edit cancelled.");
+                outln!("❌ Edit cancelled.");
                 return;
             }
             _ => unreachable!(),
@@ -145,12 +549,12 @@ impl ConfigHandler {
 
         // Save configuration
         if let Err(e) = self.config.save() {
-            eprintln!("❌ Failed to save config: {}", e);
+            errln!("❌ Failed to save config: {}", e);
             return;
         }
 
-        println!("\n✅ Configuration updated successfully!");
-        println!(
+        outln!("\n✅ Configuration updated successfully!");
+        outln!(
             "💡 Run {} to view your updated config",
             style("repogen config --view").cyan()
         );
@@ -158,10 +562,16 @@ impl ConfigHandler {
 
     /// Edit user profile information
     fn edit_user_profile(&mut self) {
-        println!("\n{}", style("👤 Edit User Profile").green().bold());
+        outln!("\n{}", style("👤 Edit User Profile").green().bold());
+
+        let usernames = self.config.usernames();
+        let mut history = prompt::seeded_history(&usernames);
+        let completion = prompt::KnownValues(usernames);
 
         let github_username = Input::with_theme(&self.theme)
             .with_prompt("GitHub username")
+            .history_with(&mut history)
+            .completion_with(&completion)
             .default(self.config.github_username.clone().unwrap_or_default())
             .allow_empty(false)
             .interact_text()
@@ -198,22 +608,17 @@ impl ConfigHandler {
 
     /// Edit repository default preferences
     fn edit_repository_defaults(&mut self) {
-        println!("\n{}", style("⚙️  Edit Repository Defaults").green().bold());
+        outln!("\n{}", style("⚙️  Edit Repository Defaults").green().bold());
 
-        let default_private = Confirm::with_theme(&self.theme)
-            .with_prompt("Make repositories private by default?")
-            .default(self.config.default_private)
-            .interact()
-            .unwrap();
+        let default_private = prompt::confirm(
+            &self.theme,
+            self.assume_yes,
+            "Make repositories private by default?",
+            self.config.default_private,
+            Assumed::Default,
+        );
 
-        let license_options = vec![
-            "None",
-            "MIT",
-            "Apache-2.0",
-            "GPL-3.0",
-            "BSD-3-Clause",
-            "Unlicense",
-        ];
+        let license_options = LICENSE_OPTIONS.to_vec();
         let current_license_index =
             self.find_option_index(&license_options, &self.config.default_license);
         let license_selection = Select::with_theme(&self.theme)
@@ -228,32 +633,29 @@ impl ConfigHandler {
             Some(license_options[license_selection].to_string())
         };
 
-        let gitignore_options = vec![
-            "None", "Node", "Python", "Rust", "Go", "Java", "C++", "Swift",
-        ];
-        let current_gitignore_index =
-            self.find_option_index(&gitignore_options, &self.config.default_gitignore);
-        let gitignore_selection = Select::with_theme(&self.theme)
-            .with_prompt("Default .gitignore template")
-            .default(current_gitignore_index)
-            .items(&gitignore_options)
+        let selected_gitignore = prompt::select_gitignore(
+            &self.theme,
+            "Default .gitignore template",
+            &gitignore::known_templates(),
+            self.config.default_gitignore.as_deref(),
+        );
+
+        let gitattributes_options = GITATTRIBUTES_OPTIONS.to_vec();
+        let current_gitattributes_index =
+            self.find_option_index(&gitattributes_options, &self.config.default_gitattributes);
+        let gitattributes_selection = Select::with_theme(&self.theme)
+            .with_prompt("Default .gitattributes template")
+            .default(current_gitattributes_index)
+            .items(&gitattributes_options)
             .interact()
             .unwrap();
-        let selected_gitignore = if gitignore_selection == 0 {
+        let selected_gitattributes = if gitattributes_selection == 0 {
             None
         } else {
-            Some(gitignore_options[gitignore_selection].to_string())
+            Some(gitattributes_options[gitattributes_selection].to_string())
         };
 
-        let editor_options = vec![
-            "None",
-            "VS Code",
-            "Vim",
-            "Emacs",
-            "Sublime Text",
-            "Atom",
-            "IntelliJ",
-        ];
+        let editor_options = EDITOR_OPTIONS.to_vec();
         let current_editor_index =
             self.find_option_index(&editor_options, &self.config.preferred_editor);
         let editor_selection = Select::with_theme(&self.theme)
@@ -268,31 +670,49 @@ impl ConfigHandler {
             Some(editor_options[editor_selection].to_string())
         };
 
+        let auto_open = prompt::confirm(
+            &self.theme,
+            self.assume_yes,
+            "Open new repositories in your browser after creation?",
+            self.config.auto_open,
+            Assumed::Default,
+        );
+
         self.config.set_preferences(
             default_private,
             selected_license,
             selected_gitignore,
             selected_editor,
         );
+        self.config
+            .set_default_gitattributes(selected_gitattributes);
+        self.config.set_auto_open(auto_open);
+
+        let disabled_features = prompt::select_disabled_features(
+            &self.theme,
+            [
+                self.config.disable_issues,
+                self.config.disable_wiki,
+                self.config.disable_projects,
+            ],
+        );
+        self.config.set_disabled_features(disabled_features);
     }
 
     /// Edit clone settings
     fn edit_clone_settings(&mut self) {
-        println!("\n{}", style("📁 Edit Clone Settings").green().bold());
+        outln!("\n{}", style("📁 Edit Clone Settings").green().bold());
 
-        let auto_clone = Confirm::with_theme(&self.theme)
-            .with_prompt("Automatically clone repositories after creation?")
-            .default(self.config.auto_clone)
-            .interact()
-            .unwrap();
+        let auto_clone = prompt::confirm(
+            &self.theme,
+            self.assume_yes,
+            "Automatically clone repositories after creation?",
+            self.config.auto_clone,
+            Assumed::Default,
+        );
 
         let clone_directory = if auto_clone {
-            let default_dir = self
-                .config
-                .clone_directory
-                .as_ref()
-                .map(|s| s.as_str())
-                .unwrap_or(".");
+            let default_dir = self.config.clone_directory.as_deref().unwrap_or(".");
 
             let input: String = Input::with_theme(&self.theme)
                 .with_prompt("Clone directory (use '.' for current directory)")
@@ -311,41 +731,47 @@ impl ConfigHandler {
         };
 
         self.config.set_clone_settings(auto_clone, clone_directory);
+
+        let clone_protocol =
+            prompt::select_clone_protocol(&self.theme, self.config.clone_over_ssh());
+        self.config.set_clone_protocol(clone_protocol);
     }
 
     /// Edit GitHub authentication
     fn edit_authentication(&mut self) {
-        println!(
+        outln!(
             "\n{}",
             style("🔐 Edit GitHub Authentication").green().bold()
         );
-        println!(
+        outln!(
             "⚠️  For security reasons, we recommend using 'repogen init --auth' to update your token."
         );
-        println!("This ensures proper validation and secure handling.\n");
+        outln!("This ensures proper validation and secure handling.\n");
 
-        let confirm = Confirm::with_theme(&self.theme)
-            .with_prompt("Do you want to continue editing authentication here?")
-            .default(false)
-            .interact()
-            .unwrap();
+        let confirm = prompt::confirm(
+            &self.theme,
+            self.assume_yes,
+            "Do you want to continue editing authentication here?",
+            false,
+            Assumed::Yes,
+        );
 
         if confirm {
-            println!("💡 Please run: {}", style("repogen init --auth").cyan());
+            outln!("💡 Please run: {}", style("repogen init --auth").cyan());
         } else {
-            println!("❌ Authentication edit cancelled.");
+            outln!("❌ Authentication edit cancelled.");
         }
     }
 
     /// Edit all configuration sections
     fn edit_all(&mut self) {
-        println!("\n{}", style("✏️  Edit All Configuration").cyan().bold());
+        outln!("\n{}", style("✏️  Edit All Configuration").cyan().bold());
 
         self.edit_user_profile();
         self.edit_repository_defaults();
         self.edit_clone_settings();
 
-        println!(
+        outln!(
             "\n💡 To update authentication, run: {}",
             style("repogen init --auth").cyan()
         );
@@ -360,74 +786,387 @@ impl ConfigHandler {
         }
     }
 
+    /// Offer safe repairs for common configuration problems
+    ///
+    /// Every fix is confirmed before it is applied.
+    fn doctor_fix(&mut self) {
+        outln!("\n{}", style("🩺 Repair Configuration").cyan().bold());
+        outln!("{}", style("━".repeat(50)).dim());
+
+        let mut changed = false;
+        let mut found = 0;
+
+        // Missing clone directory
+        if let Some(dir) = self.config.clone_directory.clone() {
+            let path = Config::expand_home(&dir);
+            if !path.is_dir() {
+                found += 1;
+                outln!("\n⚠️  Clone directory '{}' does not exist", dir);
+                if self.confirm_fix("Create it now?") {
+                    match std::fs::create_dir_all(&path) {
+                        Ok(_) => outln!("✅ Created {}", path.display()),
+                        Err(e) => errln!("❌ Failed to create directory: {}", e),
+                    }
+                }
+            }
+        }
+
+        // Values that no longer match the known options
+        let gitignore_templates = gitignore::known_templates();
+        let gitignore_options: Vec<&str> = ["None"]
+            .into_iter()
+            .chain(gitignore_templates.iter().map(String::as_str))
+            .collect();
+        let known_fields: [(&str, &[&str], &mut Option<String>); 5] = [
+            (
+                "default_license",
+                LICENSE_OPTIONS,
+                &mut self.config.default_license,
+            ),
+            (
+                "default_gitignore",
+                &gitignore_options,
+                &mut self.config.default_gitignore,
+            ),
+            (
+                "default_gitattributes",
+                GITATTRIBUTES_OPTIONS,
+                &mut self.config.default_gitattributes,
+            ),
+            (
+                "preferred_editor",
+                EDITOR_OPTIONS,
+                &mut self.config.preferred_editor,
+            ),
+            (
+                "symbol_set",
+                SYMBOL_SET_OPTIONS,
+                &mut self.config.symbol_set,
+            ),
+        ];
+        for (key, options, value) in known_fields {
+            let Some(current) = value.clone() else {
+                continue;
+            };
+            // default_gitignore may combine several templates
+            let names = if key == "default_gitignore" {
+                gitignore::split_templates(&current)
+            } else {
+                vec![current.clone()]
+            };
+            if names
+                .iter()
+                .all(|name| options[1..].contains(&name.as_str()))
+            {
+                continue;
+            }
+
+            found += 1;
+            outln!("\n⚠️  {} '{}' is not a known value", key, current);
+            let reset = prompt::confirm(
+                &self.theme,
+                self.assume_yes,
+                &format!("Reset {} to None?", key),
+                true,
+                Assumed::Yes,
+            );
+            if reset {
+                *value = None;
+                changed = true;
+                outln!("✅ Reset {}", key);
+            }
+        }
+
+        // Missing GitHub username
+        if self.config.github_username.is_none() {
+            found += 1;
+            outln!("\n⚠️  GitHub username is not set");
+            if self.confirm_fix("Enter it now?") {
+                let usernames = self.config.usernames();
+                let mut history = prompt::seeded_history(&usernames);
+                let completion = prompt::KnownValues(usernames);
+
+                let username: String = Input::with_theme(&self.theme)
+                    .with_prompt("GitHub username")
+                    .history_with(&mut history)
+                    .completion_with(&completion)
+                    .validate_with(|input: &String| -> Result<(), &str> {
+                        if input.trim().is_empty() {
+                            Err("GitHub username cannot be empty")
+                        } else {
+                            Ok(())
+                        }
+                    })
+                    .interact_text()
+                    .unwrap();
+                self.config.set_github_username(username.trim().to_string());
+                changed = true;
+                outln!("✅ GitHub username set");
+            }
+        }
+
+        // Config file readable by other users
+        #[cfg(unix)]
+        if let Ok(config_path) = Config::config_file()
+            && let Ok(metadata) = std::fs::metadata(&config_path)
+        {
+            use std::os::unix::fs::PermissionsExt;
+
+            let mode = metadata.permissions().mode() & 0o777;
+            if mode != 0o600 {
+                found += 1;
+                outln!(
+                    "\n⚠️  Config file permissions are {:o} (it may contain your token)",
+                    mode
+                );
+                if self.confirm_fix("Restrict them to 600?") {
+                    match std::fs::set_permissions(
+                        &config_path,
+                        std::fs::Permissions::from_mode(0o600),
+                    ) {
+                        Ok(_) => outln!("✅ Permissions set to 600"),
+                        Err(e) => errln!("❌ Failed to set permissions: {}", e),
+                    }
+                }
+            }
+        }
+
+        if found == 0 {
+            outln!("\n✅ No problems found. Your configuration looks healthy!");
+            return;
+        }
+
+        if changed && let Err(e) = self.config.save() {
+            errln!("❌ Failed to save config: {}", e);
+            return;
+        }
+
+        outln!("\n✅ Repair finished.");
+    }
+
+    /// Ask whether a repair should be applied
+    fn confirm_fix(&self, prompt: &str) -> bool {
+        prompt::confirm(&self.theme, self.assume_yes, prompt, true, Assumed::Yes)
+    }
+
+    /// Reset only the configuration sections the user picks
+    fn clear_sections(&mut self) {
+        outln!(
+            "\n{}",
+            style("🗑️  Clear Configuration Sections").red().bold()
+        );
+        outln!("Select the sections to reset (space to toggle, enter to confirm):\n");
+
+        let sections = vec![
+            "User Profile (username, name, email)",
+            "Repository Defaults (privacy, license, gitignore, editor, ...)",
+            "GitHub Authentication (token, OAuth client ID)",
+            "Clone Settings (auto-clone, directory, commit signing)",
+            "Advanced (creation webhook, API version, CA bundle, retries, symbol set)",
+        ];
+
+        let selected = MultiSelect::with_theme(&self.theme)
+            .with_prompt("Sections to clear")
+            .items(&sections)
+            .interact()
+            .unwrap();
+
+        if selected.is_empty() {
+            outln!("❌ Nothing selected. Clear cancelled.");
+            return;
+        }
+
+        let confirm = prompt::confirm(
+            &self.theme,
+            self.assume_yes,
+            &format!("Reset {} section(s)? This cannot be undone", selected.len()),
+            false,
+            Assumed::Yes,
+        );
+
+        if !confirm {
+            outln!("❌ Clear cancelled.");
+            return;
+        }
+
+        let defaults = Config::default();
+        for index in &selected {
+            match index {
+                0 => {
+                    self.config.github_username = None;
+                    self.config.known_usernames.clear();
+                    self.config.user_name = None;
+                    self.config.user_email = None;
+                }
+                1 => {
+                    self.config.default_private = defaults.default_private;
+                    self.config.default_license = None;
+                    self.config.default_gitignore = None;
+                    self.config.default_gitattributes = None;
+                    self.config.preferred_editor = None;
+                    self.config.auto_open = defaults.auto_open;
+                    self.config.sanitize_description = defaults.sanitize_description;
+                    self.config.default_commit_message = None;
+                    self.config.description_template = None;
+                    self.config.description_templates.clear();
+                    self.config.default_branch = None;
+                    self.config.default_labels.clear();
+                    self.config.enable_secret_scanning = defaults.enable_secret_scanning;
+                    self.config.enable_push_protection = defaults.enable_push_protection;
+                    self.config.required_reviews = None;
+                    self.config.require_code_owner_reviews = defaults.require_code_owner_reviews;
+                    self.config.dismiss_stale_reviews = defaults.dismiss_stale_reviews;
+                    self.config.disable_issues = defaults.disable_issues;
+                    self.config.disable_wiki = defaults.disable_wiki;
+                    self.config.disable_projects = defaults.disable_projects;
+                }
+                2 => {
+                    self.config.github_tokens.clear();
+                    self.config.github_host = None;
+                    self.config.api_base_url = None;
+                    self.config.oauth_client_id = None;
+                }
+                3 => {
+                    self.config.auto_clone = defaults.auto_clone;
+                    self.config.clone_recurse_submodules = defaults.clone_recurse_submodules;
+                    self.config.skip_git_config = defaults.skip_git_config;
+                    self.config.set_local_git_identity = defaults.set_local_git_identity;
+                    self.config.commit_sign = defaults.commit_sign;
+                    self.config.signing_key = None;
+                    self.config.clone_directory = None;
+                    self.config.clone_protocol = None;
+                    self.config.post_clone_command = None;
+                }
+                4 => {
+                    self.config.creation_webhook_url = None;
+                    self.config.github_api_version = None;
+                    self.config.symbol_set = None;
+                    self.config.ca_bundle = None;
+                    self.config.max_retries = None;
+                    self.config.retry_delay_ms = None;
+                    self.config.request_timeout_secs = None;
+                }
+                _ => unreachable!(),
+            }
+        }
+
+        if let Err(e) = self.config.save() {
+            errln!("❌ Failed to save config: {}", e);
+            return;
+        }
+
+        for index in selected {
+            outln!("✅ Cleared {}", sections[index]);
+        }
+    }
+
     /// Clear configuration to defaults
-    fn clear_config() {
-        println!("\n{}", style("🗑️  Clear Configuration").red().bold());
-        println!("This will reset all configuration to default values.");
-        println!(
+    fn clear_config(assume_yes: bool) {
+        outln!("\n{}", style("🗑️  Clear Configuration").red().bold());
+        outln!("This will reset all configuration to default values.");
+        outln!(
             "{}",
             style("⚠️  This action cannot be undone!").yellow().bold()
         );
 
         let theme = ColorfulTheme::default();
-        let confirm = Confirm::with_theme(&theme)
-            .with_prompt("Are you sure you want to clear all configuration?")
-            .default(false)
-            .interact()
-            .unwrap();
+        let confirm = prompt::confirm(
+            &theme,
+            assume_yes,
+            "Are you sure you want to clear all configuration?",
+            false,
+            Assumed::Yes,
+        );
 
         if !confirm {
-            println!("❌ Clear cancelled.");
+            outln!("❌ Clear cancelled.");
             return;
         }
 
         // Double confirmation for safety
-        let double_confirm = Confirm::with_theme(&theme)
-            .with_prompt("Really clear? This will delete your GitHub token and all settings!")
-            .default(false)
-            .interact()
-            .unwrap();
+        let double_confirm = prompt::confirm(
+            &theme,
+            assume_yes,
+            "Really clear? This will delete your GitHub token and all settings!",
+            false,
+            Assumed::Yes,
+        );
 
         if !double_confirm {
-            println!("❌ Clear cancelled.");
+            outln!("❌ Clear cancelled.");
             return;
         }
 
+        // Tokens in the OS keyring would outlive the file, so remove them first
+        if let Ok(config) = Config::read_file() {
+            config.forget_keyring_tokens();
+        }
+
         // Get config file path and delete it
         match Config::config_file() {
             Ok(config_path) => {
                 if config_path.exists() {
                     match std::fs::remove_file(&config_path) {
                         Ok(_) => {
-                            println!("✅ Configuration cleared successfully!");
-                            println!("💡 Run {} to set up again", style("repogen init").cyan());
+                            outln!("✅ Configuration cleared successfully!");
+                            outln!("💡 Run {} to set up again", style("repogen init").cyan());
                         }
                         Err(e) => {
-                            eprintln!("❌ Failed to delete config file: {}", e);
+                            errln!("❌ Failed to delete config file: {}", e);
                         }
                     }
                 } else {
-                    println!("ℹ️  No configuration file found. Already clear!");
+                    outln!("ℹ️  No configuration file found. Already clear!");
                 }
             }
             Err(e) => {
-                eprintln!("❌ Failed to locate config file: {}", e);
+                errln!("❌ Failed to locate config file: {}", e);
             }
         }
     }
 }
 
 /// Public function to handle config view
-pub fn handle_config_view() {
-    ConfigHandler::handle_view();
+pub fn handle_config_view(global: &cli::GlobalArgs, merge_env: bool) {
+    ConfigHandler::handle_view(global, merge_env);
+}
+
+/// Public function to handle config env
+pub fn handle_config_env(global: &cli::GlobalArgs, include_token: bool) {
+    ConfigHandler::handle_env(global, include_token);
 }
 
 /// Public function to handle config edit
-pub fn handle_config_edit() {
-    ConfigHandler::handle_edit();
+pub fn handle_config_edit(global: &cli::GlobalArgs, force: bool) {
+    ConfigHandler::handle_edit(global, force);
 }
 
 /// Public function to handle config clear
-pub fn handle_config_clear() {
-    ConfigHandler::handle_clear();
+pub fn handle_config_clear(global: &cli::GlobalArgs, force: bool) {
+    ConfigHandler::handle_clear(global, force);
+}
+
+/// Public function to handle clearing selected config sections
+pub fn handle_config_clear_sections(global: &cli::GlobalArgs, force: bool) {
+    ConfigHandler::handle_clear_sections(global, force);
+}
+
+/// Public function to handle config repair
+pub fn handle_config_doctor_fix(global: &cli::GlobalArgs, force: bool) {
+    ConfigHandler::handle_doctor_fix(global, force);
+}
+
+/// Public function to handle config export
+pub fn handle_config_export(global: &cli::GlobalArgs, path: &Path, include_secrets: bool) {
+    ConfigHandler::handle_export(global, path, include_secrets);
+}
+
+/// Public function to handle config set
+pub fn handle_config_set(global: &cli::GlobalArgs, pairs: &[String], force: bool) {
+    ConfigHandler::handle_set(global, pairs, force);
+}
+
+/// Public function to handle config lock and unlock
+pub fn handle_config_lock(global: &cli::GlobalArgs, locked: bool) {
+    ConfigHandler::handle_lock(global, locked);
 }
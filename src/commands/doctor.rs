@@ -0,0 +1,319 @@
+use crate::cli;
+use crate::outln;
+use crate::utils::config::Config;
+use crate::utils::github::{self, TokenInfo};
+use crate::utils::http::{self, ClientOptions, SendLogged};
+use crate::utils::output::Example;
+use anyhow::{Result, anyhow};
+use console::style;
+use reqwest::blocking::Client;
+use std::process::Command;
+
+/// Invocations printed by `repogen doctor --examples`
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        description: "Check that repogen is ready to create repositories",
+        command: "repogen doctor",
+    },
+    Example {
+        description: "Check another profile",
+        command: "repogen --profile work doctor",
+    },
+    Example {
+        description: "See the requests behind a failing check",
+        command: "repogen doctor --verbose",
+    },
+];
+
+/// How a single check turned out
+enum Status {
+    Pass,
+    /// Something is off, but repositories can still be created
+    Warn,
+    /// repogen can't create repositories until this is fixed
+    Fail,
+    /// Not run because an earlier check failed
+    Skip,
+}
+
+/// Checks configuration, authentication and connectivity in one go
+pub struct DoctorHandler {
+    global: cli::GlobalArgs,
+    failures: usize,
+}
+
+impl DoctorHandler {
+    /// Create a new DoctorHandler instance
+    ///
+    /// Nothing is loaded up front, since a broken config is one of the things
+    /// being checked.
+    pub fn new(_args: cli::Doctor, global: &cli::GlobalArgs) -> Self {
+        Self {
+            global: global.clone(),
+            failures: 0,
+        }
+    }
+
+    /// Run every check, failing if any check that blocks repogen failed
+    pub fn run(&mut self) -> Result<()> {
+        outln!("\n{}", style("🩺 Checking your setup").cyan().bold());
+        outln!("{}", style("━".repeat(50)).dim());
+
+        let config = self.check_config();
+        let token = self.check_token(&config);
+        self.check_git();
+        let client = self.check_network(&config);
+
+        match (client, token) {
+            (Some(client), Some(token)) => {
+                if let Some(info) = self.check_authentication(&config, &client, &token) {
+                    self.check_scopes(&info);
+                }
+            }
+            _ => {
+                self.report(
+                    Status::Skip,
+                    "Token is valid",
+                    "needs a token and a reachable API",
+                    None,
+                );
+                self.report(Status::Skip, "Token scopes", "needs a valid token", None);
+            }
+        }
+
+        if self.failures > 0 {
+            return Err(anyhow!(
+                "{} check{} failed. Follow the hints above, then run `repogen doctor` again.",
+                self.failures,
+                if self.failures == 1 { "" } else { "s" }
+            ));
+        }
+
+        outln!("\n✅ Everything looks good. You're ready to run `repogen new`.");
+        Ok(())
+    }
+
+    /// The config file exists and parses, falling back to defaults if not
+    fn check_config(&mut self) -> Config {
+        let path = match Config::config_file() {
+            Ok(path) => path,
+            Err(e) => {
+                self.report(
+                    Status::Fail,
+                    "Config file",
+                    &e.to_string(),
+                    Some("Set HOME so repogen can find its config directory"),
+                );
+                return Config::default();
+            }
+        };
+
+        if !path.exists() {
+            self.report(
+                Status::Fail,
+                "Config file",
+                &format!("not found at {}", path.display()),
+                Some("Run `repogen init` to create it"),
+            );
+            return Config::default();
+        }
+
+        let config = match Config::load() {
+            Ok(config) => config,
+            Err(e) => {
+                self.report(
+                    Status::Fail,
+                    "Config file",
+                    &format!("{:#}", e),
+                    Some("Run `repogen config --edit` to fix it, or `repogen init --force` to start over"),
+                );
+                return Config::default();
+            }
+        };
+
+        let warnings = config.validate();
+        if warnings.is_empty() {
+            self.report(
+                Status::Pass,
+                "Config file",
+                &path.display().to_string(),
+                None,
+            );
+        } else {
+            self.report(
+                Status::Warn,
+                "Config file",
+                &format!(
+                    "{} ({} warning{})",
+                    path.display(),
+                    warnings.len(),
+                    if warnings.len() == 1 { "" } else { "s" }
+                ),
+                Some("Run `repogen config --doctor-fix` to repair it"),
+            );
+            for warning in &warnings {
+                outln!("      {}", style(warning).dim());
+            }
+        }
+
+        config
+    }
+
+    /// A token is configured or given through the environment
+    fn check_token(&mut self, config: &Config) -> Option<String> {
+        match config.resolve_token(self.global.token_file.as_deref()) {
+            Ok(Some(token)) => {
+                self.report(Status::Pass, "GitHub token", "found", None);
+                Some(token)
+            }
+            Ok(None) => {
+                self.report(
+                    Status::Fail,
+                    "GitHub token",
+                    "not set",
+                    Some("Run `repogen init --auth` to authenticate"),
+                );
+                None
+            }
+            Err(e) => {
+                self.report(
+                    Status::Fail,
+                    "GitHub token",
+                    &e.to_string(),
+                    Some("Run `repogen init --auth` to authenticate"),
+                );
+                None
+            }
+        }
+    }
+
+    /// `git` can be run, which cloning and pushing local projects need
+    fn check_git(&mut self) {
+        match Command::new("git").arg("--version").output() {
+            Ok(output) if output.status.success() => {
+                let version = String::from_utf8_lossy(&output.stdout).trim().to_string();
+                self.report(Status::Pass, "git", &version, None);
+            }
+            _ => self.report(
+                Status::Warn,
+                "git",
+                "not found on PATH",
+                Some("Install git to clone new repositories or push local projects"),
+            ),
+        }
+    }
+
+    /// The API base URL answers at all, whatever the status
+    fn check_network(&mut self, config: &Config) -> Option<Client> {
+        let base = github::api_base();
+        let client = match http::client(&ClientOptions::resolve(&self.global, config)) {
+            Ok(client) => client,
+            Err(e) => {
+                self.report(
+                    Status::Fail,
+                    "API reachable",
+                    &format!("{:#}", e),
+                    Some("Check the ca_bundle setting or --ca-bundle"),
+                );
+                return None;
+            }
+        };
+
+        match client.get(&base).send_logged() {
+            Ok(response) => {
+                self.report(
+                    Status::Pass,
+                    "API reachable",
+                    &format!("{} ({})", base, response.status()),
+                    None,
+                );
+                Some(client)
+            }
+            Err(e) => {
+                let e = http::explain_timeout(e.into());
+                self.report(
+                    Status::Fail,
+                    "API reachable",
+                    &format!("{}: {:#}", base, e),
+                    Some(
+                        "Check your connection and proxy. For GitHub Enterprise Server, check api_base_url or --api-url; for TLS errors, --ca-bundle",
+                    ),
+                );
+                None
+            }
+        }
+    }
+
+    /// GitHub accepts the token
+    fn check_authentication(
+        &mut self,
+        config: &Config,
+        client: &Client,
+        token: &str,
+    ) -> Option<TokenInfo> {
+        let api_version = self
+            .global
+            .api_version
+            .clone()
+            .unwrap_or_else(|| config.api_version().to_string());
+
+        match github::validate_github_token(client, token, &api_version) {
+            Ok(info) => {
+                self.report(
+                    Status::Pass,
+                    "Token is valid",
+                    &format!("signed in as @{}", info.user.login),
+                    None,
+                );
+                Some(info)
+            }
+            Err(e) => {
+                self.report(
+                    Status::Fail,
+                    "Token is valid",
+                    &format!("{:#}", e),
+                    Some("Run `repogen init --auth` to sign in again"),
+                );
+                None
+            }
+        }
+    }
+
+    /// The token may create repositories
+    fn check_scopes(&mut self, info: &TokenInfo) {
+        let missing = info.missing_scopes();
+        match &info.scopes {
+            _ if !missing.is_empty() => self.report(
+                Status::Fail,
+                "Token scopes",
+                &format!("missing {}", missing.join(", ")),
+                Some("Run `repogen init --auth` with a token that has the repo scope"),
+            ),
+            Some(scopes) => self.report(Status::Pass, "Token scopes", &scopes.join(", "), None),
+            None => self.report(
+                Status::Pass,
+                "Token scopes",
+                "not reported for this token type, so they can't be checked",
+                None,
+            ),
+        }
+    }
+
+    /// Print one check's result, with a hint for anything that isn't passing
+    fn report(&mut self, status: Status, name: &str, detail: &str, hint: Option<&str>) {
+        let symbol = match status {
+            Status::Pass => "✅",
+            Status::Warn => "⚠️ ",
+            Status::Fail => {
+                self.failures += 1;
+                "❌"
+            }
+            Status::Skip => "⏭️ ",
+        };
+
+        outln!("  {} {}: {}", symbol, style(name).bold(), detail);
+        if let Some(hint) = hint {
+            outln!("     💡 {}", hint);
+        }
+    }
+}
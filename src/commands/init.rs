@@ -1,16 +1,70 @@
-use crate::utils::config::Config;
+use crate::cli;
+use crate::utils::config::{
+    self, Config, DEFAULT_GITHUB_HOST, DRY_RUN_TOKEN, EDITOR_OPTIONS, GITATTRIBUTES_OPTIONS,
+    LICENSE_OPTIONS, Label,
+};
+use crate::utils::github::{self, TokenInfo};
+use crate::utils::http::{self, ClientOptions, SendLogged};
+use crate::utils::output::Example;
+use crate::utils::prompt::{self, Assumed};
+use crate::utils::{browser, gitignore};
+use crate::utils::{interrupt, symbols};
+use crate::{errln, outln};
 use anyhow::{Context, Result, anyhow};
-use dialoguer::{Confirm, Input, Password, Select, theme::ColorfulTheme};
+use dialoguer::{Input, Password, Select, theme::ColorfulTheme};
 use indicatif::{ProgressBar, ProgressStyle};
-use reqwest::blocking::Client;
+use reqwest::blocking::{Client, Response};
+use reqwest::header::DATE;
 use serde::Deserialize;
-use std::thread;
-use std::time::Duration;
+use std::cell::OnceCell;
+use std::env;
+use std::path::Path;
+use std::process;
+use std::time::{Duration, Instant, SystemTime};
+
+/// How far the local clock may drift from GitHub's before OAuth is likely to break
+const MAX_CLOCK_SKEW: Duration = Duration::from_secs(5 * 60);
+
+/// Invocations printed by `repogen init --examples`
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        description: "Set up everything: profile, preferences and authentication",
+        command: "repogen init",
+    },
+    Example {
+        description: "Sign in to GitHub again without touching your preferences",
+        command: "repogen init --auth",
+    },
+    Example {
+        description: "Change your profile and preferences only",
+        command: "repogen init --meta",
+    },
+    Example {
+        description: "Create the OAuth app now and authenticate later",
+        command: "repogen init --oauth-setup",
+    },
+    Example {
+        description: "Set up a new machine from a config you already have",
+        command: "repogen init --import ~/backup/repogen.toml",
+    },
+    Example {
+        description: "Try the authentication flow without contacting GitHub",
+        command: "repogen init --auth --dry-run",
+    },
+];
 
 /// Handles the initialization workflow for repogen
 pub struct InitHandler {
     config: Config,
     theme: ColorfulTheme,
+    api_version: String,
+    assume_yes: bool,
+    http: ClientOptions,
+    /// Built on first use and shared by every request this run makes
+    client: OnceCell<Client>,
+    dry_run: bool,
+    /// `--api-url` was given, so the API base isn't asked for
+    api_url_flag: bool,
 }
 
 /// User profile information collected during init
@@ -27,9 +81,14 @@ struct UserPreferences {
     default_private: bool,
     license: Option<String>,
     gitignore_template: Option<String>,
+    gitattributes_template: Option<String>,
     preferred_editor: Option<String>,
+    auto_open: bool,
     auto_clone: bool,
     clone_directory: Option<String>,
+    clone_protocol: Option<String>,
+    default_labels: Vec<Label>,
+    disabled_features: [bool; 3],
 }
 
 /// Authentication method chosen by user
@@ -66,48 +125,105 @@ struct TokenErrorResponse {
     error_description: Option<String>,
 }
 
-/// GitHub API user information for validation
-#[derive(Debug, Deserialize)]
-struct GitHubUser {
-    login: String,
-    #[allow(dead_code)]
-    name: Option<String>,
-    #[allow(dead_code)]
-    email: Option<String>,
-}
-
 impl InitHandler {
     /// Create a new InitHandler instance
-    pub fn new() -> Self {
-        let config = Config::load().unwrap_or_else(|e| {
-            eprintln!("Warning: Could not load config: {}", e);
+    pub fn new(global: &cli::GlobalArgs) -> Self {
+        let config = Config::load_or_recover().unwrap_or_else(|e| {
+            errln!("Warning: Could not load config: {}", e);
             Config::default()
         });
+        config.print_warnings();
+        let api_version = global
+            .api_version
+            .clone()
+            .unwrap_or_else(|| config.api_version().to_string());
+        let http = ClientOptions::resolve(global, &config);
 
         Self {
             config,
             theme: ColorfulTheme::default(),
+            api_version,
+            assume_yes: global.assume_yes,
+            http,
+            client: OnceCell::new(),
+            dry_run: false,
+            api_url_flag: global.api_url.is_some(),
+        }
+    }
+
+    /// Sign in again with the device flow and save the new token
+    ///
+    /// Used when a token stops working partway through another command, so it
+    /// only works once OAuth has been set up.
+    pub fn refresh_token(global: &cli::GlobalArgs) -> Result<String> {
+        let mut handler = Self::new(global);
+        if handler.config.oauth_client_id().is_none() {
+            return Err(anyhow!(
+                "OAuth is not set up, so repogen can't sign in again here. Run `repogen init --auth`."
+            ));
         }
+
+        let token = handler.run_device_flow()?;
+        handler.config.set_github_token(token.clone());
+        if let Err(e) = handler.config.save() {
+            errln!("⚠️  Warning: Could not save the new token: {}", e);
+        }
+
+        Ok(token)
     }
 
     /// Main entry point for the initialization process
-    pub fn handle_init() {
-        let mut handler = Self::new();
+    pub fn handle_init(global: &cli::GlobalArgs, force: bool) {
+        let mut handler = Self::new(global);
+        handler.exit_if_locked(force);
         handler.run_init_workflow();
     }
 
     /// Authentication-only entry point for `repogen init --auth`
-    pub fn handle_auth_only() {
-        let mut handler = Self::new();
+    ///
+    /// With `dry_run`, the flow is simulated and a placeholder token is saved.
+    pub fn handle_auth_only(global: &cli::GlobalArgs, dry_run: bool) {
+        let mut handler = Self::new(global);
+        handler.dry_run = dry_run;
         handler.run_auth_only_workflow();
     }
 
     /// Metadata-only entry point for `repogen init --meta`
-    pub fn handle_meta_only() {
-        let mut handler = Self::new();
+    pub fn handle_meta_only(global: &cli::GlobalArgs, force: bool) {
+        let mut handler = Self::new(global);
+        handler.exit_if_locked(force);
         handler.run_meta_only_workflow();
     }
 
+    /// Import entry point for `repogen init --import <file>`
+    pub fn handle_import(global: &cli::GlobalArgs, path: &Path, force: bool) {
+        let mut handler = Self::new(global);
+        handler.exit_if_locked(force);
+
+        if let Err(e) = handler.import_config(path) {
+            errln!("❌ Import failed: {}", e);
+            process::exit(1);
+        }
+    }
+
+    /// Stop before any prompts when the profile is locked and not forced
+    fn exit_if_locked(&self, force: bool) {
+        if let Err(e) = self.config.ensure_unlocked(force) {
+            errln!("❌ {}", e);
+            process::exit(1);
+        }
+    }
+
+    /// OAuth app setup entry point for `repogen init --oauth-setup`
+    ///
+    /// Walks through creating the OAuth app and saves its client ID without
+    /// authenticating, so the app can be prepared ahead of time.
+    pub fn handle_oauth_setup_only(global: &cli::GlobalArgs) {
+        let mut handler = Self::new(global);
+        handler.guide_oauth_setup();
+        outln!("💡 Run `repogen init --auth` and choose OAuth when you're ready to authenticate.");
+    }
+
     /// Run the complete initialization workflow
     fn run_init_workflow(&mut self) {
         self.display_welcome();
@@ -125,27 +241,38 @@ impl InitHandler {
 
     /// Run authentication-only workflow
     fn run_auth_only_workflow(&mut self) {
-        println!("🔐 repogen - Authentication Setup");
-        println!("Configuring GitHub authentication only.\n");
+        outln!("🔐 repogen - Authentication Setup");
+        outln!("Configuring GitHub authentication only.\n");
 
         // Only handle authentication
         self.handle_authentication();
 
         // Save only the authentication token
         if let Err(e) = self.config.save() {
-            eprintln!("❌ Failed to save config: {}", e);
+            errln!("❌ Failed to save config: {}", e);
+            return;
+        }
+
+        if self.config.has_dry_run_token() {
+            outln!("\n🧪 Dry run complete: a placeholder token was saved.");
+            outln!(
+                "⚠️  Commands that call GitHub will refuse it until you run `repogen init --auth`."
+            );
             return;
         }
 
-        println!("\n✅ GitHub authentication configured successfully!");
-        println!("💡 Your token has been saved to ~/.config/repogen/config.toml");
-        println!("🚀 Run `repogen init --meta` to complete your profile setup.");
+        outln!("\n✅ GitHub authentication configured successfully!");
+        outln!(
+            "💡 Your token has been saved to {}",
+            Self::config_location()
+        );
+        outln!("🚀 Run `repogen init --meta` to complete your profile setup.");
     }
 
     /// Run metadata-only workflow (profile and preferences)
     fn run_meta_only_workflow(&mut self) {
-        println!("👤 repogen - Profile & Preferences Setup");
-        println!("Configuring your profile and repository preferences.\n");
+        outln!("👤 repogen - Profile & Preferences Setup");
+        outln!("Configuring your profile and repository preferences.\n");
 
         self.show_existing_config_notice();
 
@@ -156,32 +283,35 @@ impl InitHandler {
         // Save configuration
         self.save_configuration(user_profile, preferences);
 
-        println!("\n✅ Profile and preferences configured successfully!");
-        println!("💡 Your settings have been saved to ~/.config/repogen/config.toml");
+        outln!("\n✅ Profile and preferences configured successfully!");
+        outln!(
+            "💡 Your settings have been saved to {}",
+            Self::config_location()
+        );
 
         // Check if they still need to set up auth
-        if self.config.github_token.is_none() {
-            println!("⚠️  You still need to configure GitHub authentication.");
-            println!("🚀 Run `repogen init --auth` to set up your GitHub token.");
+        if self.config.token().is_none() {
+            outln!("⚠️  You still need to configure GitHub authentication.");
+            outln!("🚀 Run `repogen init --auth` to set up your GitHub token.");
         } else {
-            println!("🎉 repogen is fully configured and ready to use!");
+            outln!("🎉 repogen is fully configured and ready to use!");
         }
     }
 
     /// Display welcome message
     fn display_welcome(&self) {
-        println!("Welcome to repogen! 🚀");
-        println!("Let's set up your profile, preferences, and GitHub connection.\n");
+        outln!("Welcome to repogen! 🚀");
+        outln!("Let's set up your profile, preferences, and GitHub connection.\n");
     }
 
     /// Show notice about existing configuration if any
     fn show_existing_config_notice(&self) {
         let has_existing_config = self.config.github_username.is_some()
             || self.config.user_name.is_some()
-            || self.config.github_token.is_some();
+            || self.config.token().is_some();
 
         if has_existing_config {
-            println!(
+            outln!(
                 "💡 Found existing configuration. Press Enter to keep current values, or type new ones."
             );
         }
@@ -189,10 +319,16 @@ impl InitHandler {
 
     /// Collect user profile information
     fn collect_user_profile(&self) -> UserProfile {
-        println!("\n👤 Step 1: User Profile");
+        outln!("\n👤 Step 1: User Profile");
+
+        let usernames = self.config.usernames();
+        let mut history = prompt::seeded_history(&usernames);
+        let completion = prompt::KnownValues(usernames);
 
         let github_username = Input::with_theme(&self.theme)
             .with_prompt("GitHub username")
+            .history_with(&mut history)
+            .completion_with(&completion)
             .default(self.config.github_username.clone().unwrap_or_default())
             .validate_with(|input: &String| -> Result<(), &str> {
                 if input.trim().is_empty() {
@@ -235,48 +371,60 @@ impl InitHandler {
 
     /// Collect user preferences for repository defaults
     fn collect_user_preferences(&self) -> UserPreferences {
-        println!("\n⚙️ Step 2: Default Preferences");
+        outln!("\n⚙️ Step 2: Default Preferences");
 
         let default_private = self.ask_privacy_preference();
         let license = self.select_license();
         let gitignore_template = self.select_gitignore_template();
+        let gitattributes_template = self.select_gitattributes_template();
         let preferred_editor = self.select_preferred_editor();
+        let auto_open = self.ask_auto_open();
         let auto_clone = self.ask_auto_clone();
         let clone_directory = if auto_clone {
             self.ask_clone_directory()
         } else {
             None
         };
+        let clone_protocol = self.select_clone_protocol();
+        let default_labels = self.ask_default_labels();
+        let disabled_features = prompt::select_disabled_features(
+            &self.theme,
+            [
+                self.config.disable_issues,
+                self.config.disable_wiki,
+                self.config.disable_projects,
+            ],
+        );
 
         UserPreferences {
             default_private,
             license,
             gitignore_template,
+            gitattributes_template,
             preferred_editor,
+            auto_open,
             auto_clone,
             clone_directory,
+            clone_protocol,
+            default_labels,
+            disabled_features,
         }
     }
 
     /// Ask user about default repository privacy
     fn ask_privacy_preference(&self) -> bool {
-        Confirm::with_theme(&self.theme)
-            .with_prompt("Make repositories private by default?")
-            .default(self.config.default_private)
-            .interact()
-            .unwrap()
+        prompt::confirm(
+            &self.theme,
+            self.assume_yes,
+            "Make repositories private by default?",
+            self.config.default_private,
+            Assumed::Default,
+        )
     }
 
     /// Let user select default license
     fn select_license(&self) -> Option<String> {
-        let license_options = vec![
-            "None",
-            "MIT",
-            "Apache-2.0",
-            "GPL-3.0",
-            "BSD-3-Clause",
-            "Unlicense",
-        ];
+        let license_options = LICENSE_OPTIONS.to_vec();
 
         let current_index = self.find_option_index(&license_options, &self.config.default_license);
 
@@ -295,39 +443,49 @@ impl InitHandler {
     }
 
     /// Let user select default .gitignore template
+    ///
+    /// Offers GitHub's full template list, falling back to the built-in
+    /// options when it can't be fetched.
     fn select_gitignore_template(&self) -> Option<String> {
-        let gitignore_options = vec![
-            "None", "Node", "Python", "Rust", "Go", "Java", "C++", "Swift",
-        ];
+        let templates = self
+            .client()
+            .and_then(|client| {
+                let token = self.config.resolve_token(None).ok().flatten();
+                gitignore::available_templates(client, token.as_deref(), &self.api_version)
+            })
+            .unwrap_or_else(|_| gitignore::known_templates());
+
+        prompt::select_gitignore(
+            &self.theme,
+            "Default .gitignore template",
+            &templates,
+            self.config.default_gitignore.as_deref(),
+        )
+    }
+
+    /// Let user select the default .gitattributes template
+    fn select_gitattributes_template(&self) -> Option<String> {
+        let options = GITATTRIBUTES_OPTIONS.to_vec();
 
-        let current_index =
-            self.find_option_index(&gitignore_options, &self.config.default_gitignore);
+        let current_index = self.find_option_index(&options, &self.config.default_gitattributes);
 
         let selection = Select::with_theme(&self.theme)
-            .with_prompt("Default .gitignore template")
+            .with_prompt("Default .gitattributes template")
             .default(current_index)
-            .items(&gitignore_options)
+            .items(&options)
             .interact()
             .unwrap();
 
         if selection == 0 {
             None
         } else {
-            Some(gitignore_options[selection].to_string())
+            Some(options[selection].to_string())
         }
     }
 
     /// Let user select preferred editor
     fn select_preferred_editor(&self) -> Option<String> {
-        let editor_options = vec![
-            "None",
-            "VS Code",
-            "Vim",
-            "Emacs",
-            "Sublime Text",
-            "Atom",
-            "IntelliJ",
-        ];
+        let editor_options = EDITOR_OPTIONS.to_vec();
 
         let current_index = self.find_option_index(&editor_options, &self.config.preferred_editor);
 
@@ -345,23 +503,31 @@ impl InitHandler {
         }
     }
 
+    /// Ask user if new repositories should open in the browser after creation
+    fn ask_auto_open(&self) -> bool {
+        prompt::confirm(
+            &self.theme,
+            self.assume_yes,
+            "Open new repositories in your browser after creation?",
+            self.config.auto_open,
+            Assumed::Default,
+        )
+    }
+
     /// Ask user if they want to automatically clone repositories after creation
     fn ask_auto_clone(&self) -> bool {
-        Confirm::with_theme(&self.theme)
-            .with_prompt("Automatically clone repositories after creation?")
-            .default(self.config.auto_clone)
-            .interact()
-            .unwrap()
+        prompt::confirm(
+            &self.theme,
+            self.assume_yes,
+            "Automatically clone repositories after creation?",
+            self.config.auto_clone,
+            Assumed::Default,
+        )
     }
 
     /// Ask user for the directory where repositories should be cloned
     fn ask_clone_directory(&self) -> Option<String> {
-        let default_dir = self
-            .config
-            .clone_directory
-            .as_ref()
-            .map(|s| s.as_str())
-            .unwrap_or(".");
+        let default_dir = self.config.clone_directory.as_deref().unwrap_or(".");
 
         let input: String = Input::with_theme(&self.theme)
             .with_prompt("Clone directory (use '.' for current directory)")
@@ -377,6 +543,77 @@ impl InitHandler {
         }
     }
 
+    /// Let user choose between HTTPS and SSH clone URLs
+    fn select_clone_protocol(&self) -> Option<String> {
+        prompt::select_clone_protocol(&self.theme, self.config.clone_over_ssh())
+    }
+
+    /// Ask for the issue labels created in every new repository
+    ///
+    /// Existing labels are kept unless the user chooses to replace them.
+    fn ask_default_labels(&self) -> Vec<Label> {
+        let current = &self.config.default_labels;
+        if !current.is_empty() {
+            let names: Vec<&str> = current.iter().map(|label| label.name.as_str()).collect();
+            outln!("Current default labels: {}", names.join(", "));
+        }
+
+        let prompt = if current.is_empty() {
+            "Create default issue labels in new repositories?"
+        } else {
+            "Replace the default issue labels?"
+        };
+        if !prompt::confirm(
+            &self.theme,
+            self.assume_yes,
+            prompt,
+            false,
+            Assumed::Default,
+        ) {
+            return current.clone();
+        }
+
+        let mut labels = Vec::new();
+        loop {
+            let name: String = Input::with_theme(&self.theme)
+                .with_prompt("Label name (leave empty to finish)")
+                .allow_empty(true)
+                .interact_text()
+                .unwrap();
+            let name = name.trim();
+            if name.is_empty() {
+                break;
+            }
+
+            let color: String = Input::with_theme(&self.theme)
+                .with_prompt("Color (six hex digits)")
+                .default("ededed".to_string())
+                .validate_with(|input: &String| -> Result<(), &str> {
+                    if config::is_label_color(input.trim()) {
+                        Ok(())
+                    } else {
+                        Err("Use six hex digits, like d73a4a")
+                    }
+                })
+                .interact_text()
+                .unwrap();
+
+            let description: String = Input::with_theme(&self.theme)
+                .with_prompt("Description (optional)")
+                .allow_empty(true)
+                .interact_text()
+                .unwrap();
+
+            labels.push(Label {
+                name: name.to_string(),
+                color: color.trim().trim_start_matches('#').to_lowercase(),
+                description: Some(description.trim().to_string()).filter(|d| !d.is_empty()),
+            });
+        }
+
+        labels
+    }
+
     /// Helper method to find the index of current option in a list
     fn find_option_index(&self, options: &[&str], current_value: &Option<String>) -> usize {
         if let Some(value) = current_value {
@@ -388,28 +625,199 @@ impl InitHandler {
 
     /// Handle GitHub authentication setup
     fn handle_authentication(&mut self) {
-        println!("\n🔐 Step 3: GitHub Authentication");
+        outln!("\n🔐 Step 3: GitHub Authentication");
+        self.note_env_token();
+        self.ask_enterprise_host();
 
-        if self.config.github_token.is_some() {
+        if self.config.token().is_some() {
             if self.ask_keep_existing_token() {
-                println!("✅ Keeping existing GitHub token");
+                outln!("✅ Keeping existing GitHub token");
                 return;
             } else {
-                self.config.github_token = None;
+                self.config.clear_github_token();
             }
         }
 
         let auth_method = self.select_auth_method();
-        self.execute_authentication(auth_method);
+        if self.dry_run {
+            self.simulate_authentication(auth_method);
+        } else {
+            self.execute_authentication(auth_method);
+        }
+    }
+
+    /// Ask whether GitHub Enterprise Server is used, and where
+    ///
+    /// Sets both the API base URL and the host the token is stored under.
+    fn ask_enterprise_host(&mut self) {
+        if self.api_url_flag {
+            return;
+        }
+
+        let enterprise = prompt::confirm(
+            &self.theme,
+            self.assume_yes,
+            "Do you use GitHub Enterprise Server?",
+            self.config.api_base_url.is_some(),
+            Assumed::Default,
+        );
+        if !enterprise {
+            self.config.github_host = None;
+            self.config.api_base_url = None;
+            github::set_api_base(None);
+            return;
+        }
+
+        let current = Some(self.config.github_host()).filter(|host| *host != DEFAULT_GITHUB_HOST);
+        let mut input = Input::<String>::with_theme(&self.theme)
+            .with_prompt("Enterprise Server host (e.g. github.example.com)")
+            .validate_with(|input: &String| -> Result<(), &str> {
+                let host = input.trim();
+                if host.is_empty() || host.contains('/') || host.contains(' ') {
+                    Err("Enter just the host name, without https:// or a path")
+                } else {
+                    Ok(())
+                }
+            });
+        if let Some(current) = current {
+            input = input.default(current.to_string());
+        }
+        let host = input.interact_text().unwrap().trim().to_lowercase();
+
+        let api_base_url = format!("https://{}/api/v3", host);
+        outln!("🏢 Using the API at {}", api_base_url);
+        github::set_api_base(Some(&api_base_url));
+        self.config.api_base_url = Some(api_base_url);
+        self.config.github_host = Some(host);
+    }
+
+    /// Merge a config file into this profile, checking its token before keeping it
+    fn import_config(&mut self, path: &Path) -> Result<()> {
+        outln!("\n📥 Importing configuration from {}", path.display());
+
+        let previous_tokens = self.config.github_tokens.clone();
+        let imported_tokens = self.config.merge_file(path)?;
+        if !self.api_url_flag {
+            github::set_api_base(self.config.api_base_url.as_deref());
+        }
+
+        for (host, token) in imported_tokens {
+            let previous = previous_tokens.get(&host);
+            if !self.accept_imported_token(&host, &token, previous) {
+                match previous {
+                    Some(previous) => self.config.github_tokens.insert(host, previous.clone()),
+                    None => self.config.github_tokens.remove(&host),
+                };
+            }
+        }
+
+        self.config.save()?;
+        outln!(
+            "✅ Configuration imported into profile '{}'",
+            config::active_profile()
+        );
+        for warning in self.config.validate() {
+            errln!("⚠️  Config: {}", warning);
+        }
+
+        Ok(())
+    }
+
+    /// Decide whether an imported token replaces what's saved for `host`
+    ///
+    /// The token for the active host is validated against GitHub first. Tokens
+    /// for other hosts can't be checked from here, so they're kept as they are.
+    fn accept_imported_token(&self, host: &str, token: &str, previous: Option<&String>) -> bool {
+        if previous.is_some_and(|previous| previous == token) {
+            return true;
+        }
+
+        if host == self.config.github_host() {
+            print!(
+                "{}",
+                symbols::apply("🔍 Validating the imported token with GitHub... ")
+            );
+            match self.validate_github_token(token) {
+                Ok(info) => {
+                    outln!("✅ Authenticated as {}", info.user.login);
+                    if !self.accept_missing_scopes(&info) {
+                        return false;
+                    }
+                }
+                Err(e) => {
+                    outln!("❌ Failed!");
+                    errln!("⚠️  Not importing the {} token: {}", host, e);
+                    return false;
+                }
+            }
+        } else {
+            outln!(
+                "ℹ️  Imported the {} token without checking it (not the active host)",
+                host
+            );
+        }
+
+        if previous.is_none() {
+            return true;
+        }
+
+        errln!(
+            "⚠️  The imported file replaces the {} token saved in this profile",
+            host
+        );
+        prompt::confirm(
+            &self.theme,
+            self.assume_yes,
+            "Replace the saved token?",
+            true,
+            Assumed::Yes,
+        )
+    }
+
+    /// Point out a token in the environment, which wins over the one saved here
+    fn note_env_token(&self) {
+        if let Some((name, _)) = Config::env_token(|key| env::var_os(key)) {
+            outln!(
+                "ℹ️  {} is set and takes precedence over the token saved in the config file.",
+                name
+            );
+        }
+    }
+
+    /// Walk through an authentication method without contacting GitHub
+    ///
+    /// Saves [`DRY_RUN_TOKEN`], which every command refuses to send.
+    fn simulate_authentication(&mut self, method: AuthMethod) {
+        outln!("\n🧪 Dry run: GitHub will not be contacted");
+
+        match method {
+            AuthMethod::OAuth => {
+                outln!("📝 Would request a device code from GitHub");
+                outln!("\n┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓");
+                outln!("┃  Please visit: {}/login/device   ┃", github::web_base());
+                outln!("┃  And enter code: XXXX-XXXX                       ┃");
+                outln!("┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛\n");
+                outln!("⏳ Would wait for you to authorize the device");
+            }
+            AuthMethod::PersonalAccessToken => {
+                outln!("📝 Would ask for a Personal Access Token");
+                outln!("🔍 Would validate it against the GitHub API");
+            }
+        }
+
+        self.config.set_github_token(DRY_RUN_TOKEN.to_string());
+        outln!("✅ Stored a placeholder token (not valid on GitHub)");
     }
 
     /// Ask if user wants to keep existing token
     fn ask_keep_existing_token(&self) -> bool {
-        Confirm::with_theme(&self.theme)
-            .with_prompt("You already have a GitHub token configured. Keep it?")
-            .default(true)
-            .interact()
-            .unwrap()
+        prompt::confirm(
+            &self.theme,
+            self.assume_yes,
+            "You already have a GitHub token configured. Keep it?",
+            true,
+            Assumed::Yes,
+        )
     }
 
     /// Let user select authentication method
@@ -443,9 +851,12 @@ impl InitHandler {
 
     /// Handle Personal Access Token authentication
     fn handle_pat_authentication(&mut self) {
-        println!("\n📝 Using Personal Access Token authentication");
-        println!("💡 Create a token at: https://github.com/settings/tokens/new");
-        println!("   Required scopes: repo, user\n");
+        outln!("\n📝 Using Personal Access Token authentication");
+        outln!(
+            "💡 Create a token at: {}/settings/tokens/new",
+            github::web_base()
+        );
+        outln!("   Required scopes: repo, user\n");
 
         let token = Password::with_theme(&self.theme)
             .with_prompt("Enter your GitHub Personal Access Token")
@@ -460,32 +871,59 @@ impl InitHandler {
             .unwrap();
 
         // Validate token with GitHub API
-        print!("🔍 Validating token with GitHub... ");
-        match Self::validate_github_token(&token) {
-            Ok(user) => {
-                println!("✅ Success!");
-                println!("👤 Authenticated as: {}", user.login);
+        print!("{}", symbols::apply("🔍 Validating token with GitHub... "));
+        match self.validate_github_token(&token) {
+            Ok(info) => {
+                outln!("✅ Success!");
+                match &info.scopes {
+                    Some(scopes) if !scopes.is_empty() => outln!(
+                        "👤 Authenticated as {} with scopes: {}",
+                        info.user.login,
+                        scopes.join(", ")
+                    ),
+                    Some(_) => outln!("👤 Authenticated as {} with no scopes", info.user.login),
+                    None => outln!(
+                        "👤 Authenticated as {} (GitHub doesn't report scopes for this token type)",
+                        info.user.login
+                    ),
+                }
+
+                if !self.accept_missing_scopes(&info) {
+                    let retry = prompt::confirm(
+                        &self.theme,
+                        self.assume_yes,
+                        "Enter a different token?",
+                        true,
+                        Assumed::No,
+                    );
+                    if retry {
+                        self.handle_pat_authentication();
+                    }
+                    return;
+                }
 
                 // Optionally use the validated username
                 if self.config.github_username.is_none() {
-                    self.config.github_username = Some(user.login);
+                    self.config.set_github_username(info.user.login);
                 }
 
                 self.config.set_github_token(token);
             }
             Err(e) => {
-                println!("❌ Failed!");
-                eprintln!("Error: {}", e);
-                eprintln!("\n⚠️  Token validation failed. Please check:");
-                eprintln!("   1. Token is correct and not expired");
-                eprintln!("   2. Token has required scopes (repo, user)");
-                eprintln!("   3. You have internet connection");
-
-                let retry = Confirm::with_theme(&self.theme)
-                    .with_prompt("Would you like to try again?")
-                    .default(true)
-                    .interact()
-                    .unwrap();
+                outln!("❌ Failed!");
+                errln!("Error: {}", e);
+                errln!("\n⚠️  Token validation failed. Please check:");
+                errln!("   1. Token is correct and not expired");
+                errln!("   2. Token has required scopes (repo, user)");
+                errln!("   3. You have internet connection");
+
+                let retry = prompt::confirm(
+                    &self.theme,
+                    self.assume_yes,
+                    "Would you like to try again?",
+                    true,
+                    Assumed::No,
+                );
 
                 if retry {
                     self.handle_pat_authentication();
@@ -494,30 +932,67 @@ impl InitHandler {
         }
     }
 
+    /// Warn about required scopes the token lacks and ask whether to save it anyway
+    ///
+    /// Returns `true` when the token should be saved.
+    fn accept_missing_scopes(&self, info: &TokenInfo) -> bool {
+        let missing = info.missing_scopes();
+        if missing.is_empty() {
+            return true;
+        }
+
+        errln!(
+            "\n⚠️  This token is missing the {} scope{}, so repogen can't create repositories with it.",
+            missing.join(", "),
+            if missing.len() == 1 { "" } else { "s" }
+        );
+        errln!(
+            "💡 Create a new token with {} at: {}/settings/tokens/new",
+            github::REQUIRED_SCOPES.join(", "),
+            github::web_base()
+        );
+
+        prompt::confirm(
+            &self.theme,
+            self.assume_yes,
+            "Save this token anyway?",
+            false,
+            Assumed::No,
+        )
+    }
+
     /// Handle OAuth authentication using GitHub Device Flow
     fn handle_oauth_authentication(&mut self) {
-        println!("\n🌐 OAuth Browser Authentication");
-        println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━\n");
-
-        // Check if OAuth client ID is already configured
-        if self.config.oauth_client_id.is_none() {
-            println!("📋 OAuth Setup Required");
-            println!("\nOAuth authentication requires a GitHub OAuth App.");
-            println!("This is a one-time setup that takes about 2 minutes.\n");
-
-            let setup_now = Confirm::with_theme(&self.theme)
-                .with_prompt("Would you like to set up OAuth now?")
-                .default(false)
-                .interact()
-                .unwrap();
+        outln!("\n🌐 OAuth Browser Authentication");
+        outln!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━\n");
+
+        // Check if OAuth client ID is already configured or bundled
+        if self.config.uses_bundled_oauth_app() {
+            outln!(
+                "Using repogen's built-in OAuth app. To use your own, run `repogen init --oauth-setup` first.\n"
+            );
+        } else if self.config.oauth_client_id().is_none() {
+            outln!("📋 OAuth Setup Required");
+            outln!("\nOAuth authentication requires a GitHub OAuth App.");
+            outln!("This is a one-time setup that takes about 2 minutes.\n");
+
+            let setup_now = prompt::confirm(
+                &self.theme,
+                self.assume_yes,
+                "Would you like to set up OAuth now?",
+                false,
+                Assumed::Default,
+            );
 
             if !setup_now {
-                println!("\n💡 No problem! You can use Personal Access Token instead.");
-                let use_pat = Confirm::with_theme(&self.theme)
-                    .with_prompt("Use Personal Access Token?")
-                    .default(true)
-                    .interact()
-                    .unwrap();
+                outln!("\n💡 No problem! You can use Personal Access Token instead.");
+                let use_pat = prompt::confirm(
+                    &self.theme,
+                    self.assume_yes,
+                    "Use Personal Access Token?",
+                    true,
+                    Assumed::Yes,
+                );
 
                 if use_pat {
                     self.handle_pat_authentication();
@@ -527,25 +1002,28 @@ impl InitHandler {
 
             // Guide user through OAuth app creation
             self.guide_oauth_setup();
+            outln!("🎉 Now let's authenticate...\n");
         }
 
         // Run OAuth device flow with configured client ID
-        match self.run_device_flow() {
+        match self.run_device_flow().map_err(http::explain_timeout) {
             Ok(token) => {
-                println!("✅ Successfully authenticated with GitHub!");
+                outln!("✅ Successfully authenticated with GitHub!");
                 self.config.set_github_token(token);
             }
             Err(e) => {
-                eprintln!("❌ OAuth authentication failed: {}", e);
-                println!("\n💡 You can try:");
-                println!("   1. Run the setup again: repogen init --auth");
-                println!("   2. Use a Personal Access Token instead");
-
-                let use_pat = Confirm::with_theme(&self.theme)
-                    .with_prompt("Would you like to use Personal Access Token?")
-                    .default(true)
-                    .interact()
-                    .unwrap();
+                errln!("❌ OAuth authentication failed: {}", e);
+                outln!("\n💡 You can try:");
+                outln!("   1. Run the setup again: repogen init --auth");
+                outln!("   2. Use a Personal Access Token instead");
+
+                let use_pat = prompt::confirm(
+                    &self.theme,
+                    self.assume_yes,
+                    "Would you like to use Personal Access Token?",
+                    true,
+                    Assumed::Yes,
+                );
 
                 if use_pat {
                     self.handle_pat_authentication();
@@ -556,38 +1034,38 @@ impl InitHandler {
 
     /// Guide user through OAuth app setup
     fn guide_oauth_setup(&mut self) {
-        println!("\n📝 OAuth App Setup Guide");
-        println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━\n");
-
-        println!("Step 1: Create a GitHub OAuth App");
-        println!("   → Open: https://github.com/settings/developers\n");
-
-        if webbrowser::open("https://github.com/settings/developers").is_ok() {
-            println!("✅ Browser opened automatically\n");
-        } else {
-            println!("⚠️  Please open the URL manually in your browser\n");
-        }
-
-        println!("Step 2: Click 'New OAuth App' (or 'Register a new application')\n");
-
-        println!("Step 3: Fill in the application details:");
-        println!("   • Application name: repogen");
-        println!("   • Homepage URL: https://github.com/mgale694/repogen");
-        println!("   • Authorization callback URL: http://127.0.0.1");
-        println!("   • Description: CLI tool for GitHub repository creation\n");
-
-        println!("Step 4: After creating the app:");
-        println!("   • Check the box: ☑️  Enable Device Flow");
-        println!("   • Copy the Client ID (starts with 'Iv1.' or similar)\n");
-
-        Confirm::with_theme(&self.theme)
-            .with_prompt("Press Enter when you've created the app and have the Client ID ready")
-            .default(true)
-            .interact()
-            .unwrap();
+        outln!("\n📝 OAuth App Setup Guide");
+        outln!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━\n");
+
+        outln!("Step 1: Create a GitHub OAuth App");
+        let developer_settings = format!("{}/settings/developers", github::web_base());
+        outln!("   → Open: {}\n", developer_settings);
+
+        browser::open(&developer_settings);
+        outln!();
+
+        outln!("Step 2: Click 'New OAuth App' (or 'Register a new application')\n");
+
+        outln!("Step 3: Fill in the application details:");
+        outln!("   • Application name: repogen");
+        outln!("   • Homepage URL: https://github.com/mgale694/repogen");
+        outln!("   • Authorization callback URL: http://127.0.0.1");
+        outln!("   • Description: CLI tool for GitHub repository creation\n");
+
+        outln!("Step 4: After creating the app:");
+        outln!("   • Check the box: ☑️  Enable Device Flow");
+        outln!("   • Copy the Client ID (starts with 'Iv1.' or similar)\n");
+
+        prompt::confirm(
+            &self.theme,
+            self.assume_yes,
+            "Press Enter when you've created the app and have the Client ID ready",
+            true,
+            Assumed::Yes,
+        );
 
         // Prompt for client ID
-        println!("\n📋 Enter OAuth App Details\n");
+        outln!("\n📋 Enter OAuth App Details\n");
 
         let client_id = Input::with_theme(&self.theme)
             .with_prompt("GitHub OAuth App Client ID")
@@ -608,62 +1086,63 @@ impl InitHandler {
             .set_oauth_client_id(client_id.trim().to_string());
 
         if let Err(e) = self.config.save() {
-            eprintln!("⚠️  Warning: Could not save client ID to config: {}", e);
-            println!("You may need to enter it again next time.");
+            errln!("⚠️  Warning: Could not save client ID to config: {}", e);
+            outln!("You may need to enter it again next time.");
         } else {
-            println!("\n✅ Client ID saved to config!");
+            outln!("\n✅ Client ID saved to config!");
         }
 
-        println!("\n🎉 OAuth setup complete! Now let's authenticate...\n");
+        outln!("\n🎉 OAuth setup complete!\n");
     }
 
     /// Run the GitHub OAuth Device Flow
     ///
     /// Uses the OAuth client ID stored in config to authenticate via GitHub's device flow.
     fn run_device_flow(&self) -> Result<String> {
-        const DEVICE_CODE_URL: &str = "https://github.com/login/device/code";
-        const TOKEN_URL: &str = "https://github.com/login/oauth/access_token";
+        let device_code_url = format!("{}/login/device/code", github::web_base());
+        let token_url = format!("{}/login/oauth/access_token", github::web_base());
 
-        // Get client ID from config
-        let client_id =
-            self.config.oauth_client_id.as_ref().ok_or_else(|| {
-                anyhow!("OAuth client ID not configured. Please run setup first.")
-            })?;
+        // Get client ID from config, falling back to the bundled app
+        let client_id = self
+            .config
+            .oauth_client_id()
+            .ok_or_else(|| anyhow!("OAuth client ID not configured. Please run setup first."))?;
 
-        let client = Client::new();
+        let client = self.client()?;
 
         // Step 1: Request device and user verification codes
-        println!("📝 Requesting device code from GitHub...");
-        let device_response: DeviceCodeResponse = client
-            .post(DEVICE_CODE_URL)
+        outln!("📝 Requesting device code from GitHub...");
+        let response = client
+            .post(&device_code_url)
             .header("Accept", "application/json")
-            .form(&[("client_id", client_id.as_str()), ("scope", "repo user")])
-            .send()
-            .context("Failed to request device code")?
+            .form(&[("client_id", client_id), ("scope", "repo user")])
+            .send_logged()
+            .context("Failed to request device code")?;
+
+        // A badly wrong clock makes codes look expired, so check it up front
+        let clock_skewed = Self::warn_about_clock_skew(&response);
+
+        let device_response: DeviceCodeResponse = response
             .json()
             .context("Failed to parse device code response")?;
 
         // Step 2: Show user code and open browser
-        println!("\n┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓");
-        println!("┃  Please visit: {}   ┃", device_response.verification_uri);
-        println!(
+        outln!("\n┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓");
+        outln!("┃  Please visit: {}   ┃", device_response.verification_uri);
+        outln!(
             "┃  And enter code: {}                       ┃",
             device_response.user_code
         );
-        println!("┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛\n");
+        outln!("┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛\n");
 
         // Try to open browser automatically
-        if webbrowser::open(&device_response.verification_uri).is_ok() {
-            println!("✅ Browser opened automatically");
-        } else {
-            println!("⚠️  Could not open browser automatically");
-        }
+        browser::open(&device_response.verification_uri);
 
         // Step 3: Poll for authorization
         let pb = ProgressBar::new_spinner();
         pb.set_style(
             ProgressStyle::default_spinner()
-                .tick_chars("⠁⠂⠄⡀⢀⠠⠐⠈ ")
+                .tick_chars(symbols::spinner_ticks())
                 .template("{spinner:.cyan} {msg}")
                 .unwrap(),
         );
@@ -674,32 +1153,56 @@ impl InitHandler {
         let mut attempts = 0;
         let max_attempts = (device_response.expires_in / device_response.interval) as usize;
 
+        // Ctrl-C stops the wait cleanly instead of killing the spinner mid-line
+        let watch = interrupt::Watch::start();
+        let cancelled = || {
+            pb.finish_with_message(symbols::apply("❌ Cancelled").into_owned());
+            anyhow!("Authorization cancelled with Ctrl-C")
+        };
+
+        // Codes that die well before `expires_in` point at the clock rather than the user
+        let started = Instant::now();
+        let clock_hint = || {
+            let expires_in = Duration::from_secs(device_response.expires_in);
+            if clock_skewed || started.elapsed() < expires_in / 2 {
+                errln!(
+                    "💡 The code expired sooner than expected. Check that your system clock, time zone and date are correct."
+                );
+            }
+        };
+
         loop {
             if attempts >= max_attempts {
-                pb.finish_with_message("❌ Device code expired");
+                pb.finish_with_message(symbols::apply("❌ Device code expired").into_owned());
+                clock_hint();
                 return Err(anyhow!("Device code expired. Please try again."));
             }
 
-            thread::sleep(interval);
+            if watch.sleep(interval) {
+                return Err(cancelled());
+            }
             attempts += 1;
 
             // Poll for token
             let response = client
-                .post(TOKEN_URL)
+                .post(&token_url)
                 .header("Accept", "application/json")
                 .form(&[
-                    ("client_id", client_id.as_str()),
+                    ("client_id", client_id),
                     ("device_code", &device_response.device_code),
                     ("grant_type", "urn:ietf:params:oauth:grant-type:device_code"),
                 ])
-                .send()
+                .send_logged()
                 .context("Failed to poll for token")?;
+            if watch.interrupted() {
+                return Err(cancelled());
+            }
 
             let text = response.text().context("Failed to read response")?;
 
             // Try to parse as success response first
             if let Ok(token_response) = serde_json::from_str::<TokenResponse>(&text) {
-                pb.finish_with_message("✅ Authorization successful!");
+                pb.finish_with_message(symbols::apply("✅ Authorization successful!").into_owned());
                 return Ok(token_response.access_token);
             }
 
@@ -713,19 +1216,31 @@ impl InitHandler {
                     "slow_down" => {
                         // We're polling too fast, add 5 seconds to interval
                         pb.set_message("Slowing down polling...");
-                        thread::sleep(Duration::from_secs(5));
+                        if watch.sleep(Duration::from_secs(5)) {
+                            return Err(cancelled());
+                        }
                         continue;
                     }
                     "expired_token" => {
-                        pb.finish_with_message("❌ Device code expired");
+                        pb.finish_with_message(
+                            symbols::apply("❌ Device code expired").into_owned(),
+                        );
+                        clock_hint();
                         return Err(anyhow!("Device code expired. Please try again."));
                     }
                     "access_denied" => {
-                        pb.finish_with_message("❌ Access denied");
+                        pb.finish_with_message(symbols::apply("❌ Access denied").into_owned());
                         return Err(anyhow!("User denied access"));
                     }
                     _ => {
-                        pb.finish_with_message("❌ Authentication failed");
+                        pb.finish_with_message(
+                            symbols::apply("❌ Authentication failed").into_owned(),
+                        );
+                        if clock_skewed {
+                            errln!(
+                                "💡 Your system clock looks wrong, which can cause this. Fix it and try again."
+                            );
+                        }
                         return Err(anyhow!(
                             "Authentication error: {} - {}",
                             error_response.error,
@@ -736,39 +1251,61 @@ impl InitHandler {
             }
 
             // Unexpected response
-            pb.finish_with_message("❌ Unexpected response");
+            pb.finish_with_message(symbols::apply("❌ Unexpected response").into_owned());
             return Err(anyhow!("Unexpected response from GitHub: {}", text));
         }
     }
 
-    /// Validate a GitHub token by making an API call
-    fn validate_github_token(token: &str) -> Result<GitHubUser> {
-        let client = Client::new();
+    /// Compare GitHub's `Date` header with the local clock and warn when they
+    /// disagree by more than [`MAX_CLOCK_SKEW`]
+    ///
+    /// Returns whether a skew was found.
+    fn warn_about_clock_skew(response: &Response) -> bool {
+        let Some(server_time) = response
+            .headers()
+            .get(DATE)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| httpdate::parse_http_date(value).ok())
+        else {
+            return false;
+        };
 
-        let response = client
-            .get("https://api.github.com/user")
-            .header("Authorization", format!("Bearer {}", token))
-            .header("User-Agent", "repogen-cli")
-            .header("Accept", "application/vnd.github+json")
-            .header("X-GitHub-Api-Version", "2022-11-28")
-            .send()
-            .context("Failed to validate token with GitHub API")?;
-
-        if !response.status().is_success() {
-            let status = response.status();
-            let error_text = response.text().unwrap_or_default();
-            return Err(anyhow!(
-                "GitHub API returned error {}: {}",
-                status,
-                error_text
-            ));
+        let now = SystemTime::now();
+        let (skew, direction) = match now.duration_since(server_time) {
+            Ok(ahead) => (ahead, "ahead of"),
+            Err(e) => (e.duration(), "behind"),
+        };
+        if skew <= MAX_CLOCK_SKEW {
+            return false;
         }
 
-        let user: GitHubUser = response
-            .json()
-            .context("Failed to parse GitHub user response")?;
+        let minutes = skew.as_secs() / 60;
+        let amount = match minutes {
+            0..120 => format!("{} minutes", minutes),
+            120..2880 => format!("{} hours", minutes / 60),
+            _ => format!("{} days", minutes / 1440),
+        };
+        errln!(
+            "⚠️  Your system clock is about {} {} GitHub's. OAuth sign-in can fail until it's corrected.",
+            amount,
+            direction
+        );
+        true
+    }
+
+    /// The HTTP client for this run, built on first use
+    fn client(&self) -> Result<&Client> {
+        if let Some(client) = self.client.get() {
+            return Ok(client);
+        }
+        let client = http::client(&self.http)?;
+        Ok(self.client.get_or_init(|| client))
+    }
 
-        Ok(user)
+    /// Validate a GitHub token with this run's shared client
+    fn validate_github_token(&self, token: &str) -> Result<TokenInfo> {
+        github::validate_github_token(self.client()?, token, &self.api_version)
+            .map_err(http::explain_timeout)
     }
 
     /// Save all configuration to file
@@ -782,20 +1319,36 @@ impl InitHandler {
             preferences.gitignore_template,
             preferences.preferred_editor,
         );
+        self.config
+            .set_default_gitattributes(preferences.gitattributes_template);
+        self.config.set_auto_open(preferences.auto_open);
 
         self.config
             .set_clone_settings(preferences.auto_clone, preferences.clone_directory);
+        self.config.set_clone_protocol(preferences.clone_protocol);
+        self.config.set_default_labels(preferences.default_labels);
+        self.config
+            .set_disabled_features(preferences.disabled_features);
 
         if let Err(e) = self.config.save() {
-            eprintln!("❌ Failed to save config: {}", e);
-            return;
+            errln!("❌ Failed to save config: {}", e);
         }
     }
 
+    /// Where this profile's config is saved, for messages
+    fn config_location() -> String {
+        Config::config_file()
+            .map(|path| path.display().to_string())
+            .unwrap_or_else(|_| "your repogen config".to_string())
+    }
+
     /// Display completion message
     fn display_completion_message(&self) {
-        println!("\n🎉 repogen is now fully configured and ready to use!");
-        println!("💡 Your preferences have been saved to ~/.config/repogen/config.toml");
-        println!("🚀 Try running: repogen new my-awesome-project");
+        outln!("\n🎉 repogen is now fully configured and ready to use!");
+        outln!(
+            "💡 Your preferences have been saved to {}",
+            Self::config_location()
+        );
+        outln!("🚀 Try running: repogen new my-awesome-project");
     }
 }
@@ -0,0 +1,71 @@
+use crate::cli;
+use crate::outln;
+use crate::utils::browser;
+use crate::utils::last_repo::{self, LastRepo};
+use crate::utils::output::{self, Example, OutputFormat};
+use anyhow::{Result, anyhow};
+use console::style;
+
+/// Invocations printed by `repogen last --examples`
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        description: "Find the URL of the repository you just created",
+        command: "repogen last",
+    },
+    Example {
+        description: "Open it in your browser again",
+        command: "repogen last --open",
+    },
+    Example {
+        description: "Just the URL, for scripts",
+        command: "repogen last --porcelain | cut -f2",
+    },
+];
+
+/// Shows the repository `repogen new` created most recently
+pub struct LastHandler {
+    args: cli::Last,
+}
+
+impl LastHandler {
+    /// Create a new LastHandler instance
+    pub fn new(args: cli::Last) -> Self {
+        // Keep browser messages out of the porcelain record
+        if args.porcelain {
+            output::set_quiet();
+        }
+        Self { args }
+    }
+
+    /// Print the last created repository, opening it if asked
+    pub fn run(&self) -> Result<()> {
+        let last = last_repo::load()?.ok_or_else(|| {
+            anyhow!("No repository created yet. Run `repogen new <name>` to create one.")
+        })?;
+
+        match OutputFormat::from_porcelain(self.args.porcelain) {
+            OutputFormat::Human => Self::print_details(&last),
+            OutputFormat::Porcelain => {
+                output::print_porcelain(&[&last.full_name, &last.html_url, &last.created_at])
+            }
+        }
+
+        if self.args.open {
+            outln!("\n🌐 Opening the repository in your browser...");
+            browser::open(&last.html_url);
+        }
+
+        Ok(())
+    }
+
+    /// Print the repository as labelled lines
+    fn print_details(last: &LastRepo) {
+        outln!("\n{}", style("📌 Last created repository").cyan().bold());
+        outln!("{}", style("━".repeat(50)).dim());
+        outln!("  Name: {}", style(&last.full_name).green());
+        outln!("  URL: {}", style(&last.html_url).cyan().underlined());
+        if !last.created_at.is_empty() {
+            outln!("  Created: {}", last.created_at);
+        }
+    }
+}
@@ -0,0 +1,214 @@
+use crate::cli;
+use crate::outln;
+use crate::utils::config::Config;
+use crate::utils::github::{self, RepoSummary};
+use crate::utils::http::{self, ClientOptions};
+use crate::utils::output::{self, Example, OutputFormat};
+use anyhow::{Context, Result, anyhow};
+use console::Style;
+use reqwest::blocking::Client;
+
+/// Invocations printed by `repogen list --examples`
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        description: "List all your repositories",
+        command: "repogen list",
+    },
+    Example {
+        description: "Repositories created this quarter",
+        command: "repogen list --since-created 2026-07-01 --until 2026-09-30",
+    },
+    Example {
+        description: "The ten newest private repositories",
+        command: "repogen list --private --limit 10",
+    },
+    Example {
+        description: "Names of public repositories, for scripts",
+        command: "repogen list --public --porcelain | cut -f1",
+    },
+];
+
+/// Handles listing existing repositories
+pub struct ListHandler {
+    args: cli::List,
+    api_version: String,
+    token: Option<String>,
+    client: Client,
+}
+
+impl ListHandler {
+    /// Create a new ListHandler instance
+    pub fn new(args: cli::List, global: &cli::GlobalArgs) -> Result<Self> {
+        let config = Config::load_or_recover().context("Failed to load configuration")?;
+        config.print_warnings();
+        let api_version = global
+            .api_version
+            .clone()
+            .unwrap_or_else(|| config.api_version().to_string());
+        let token = config.resolve_token(global.token_file.as_deref())?;
+        let client = http::client(&ClientOptions::resolve(global, &config))?;
+
+        Ok(Self {
+            args,
+            api_version,
+            token,
+            client,
+        })
+    }
+
+    /// Fetch and print the repositories
+    pub fn run(&self) -> Result<()> {
+        let cyan = Style::new().cyan().bold();
+
+        // Validate filters before making any request
+        let since = self
+            .args
+            .since_created
+            .as_deref()
+            .map(|date| Self::parse_date("--since-created", date))
+            .transpose()?;
+        let until = self
+            .args
+            .until
+            .as_deref()
+            .map(|date| Self::parse_date("--until", date))
+            .transpose()?;
+
+        if let (Some(since), Some(until)) = (since, until)
+            && since > until
+        {
+            return Err(anyhow!(
+                "--since-created ({}) is after --until ({})",
+                since,
+                until
+            ));
+        }
+
+        let token = self.token.as_ref().ok_or_else(|| {
+            anyhow!("No GitHub token found. Run `repogen init --auth` to authenticate.")
+        })?;
+
+        let format = OutputFormat::from_porcelain(self.args.porcelain);
+        if format == OutputFormat::Human {
+            outln!("\n{}", cyan.apply_to("📋 Fetching repositories..."));
+        }
+
+        let repos: Vec<RepoSummary> =
+            github::list_repos(&self.client, token, &self.api_version, None)?
+                .into_iter()
+                .filter(|repo| {
+                    // `created_at` is an ISO 8601 timestamp, so its date prefix compares as text
+                    let created = repo.created_at.get(..10).unwrap_or(&repo.created_at);
+                    since.is_none_or(|since| created >= since)
+                        && until.is_none_or(|until| created <= until)
+                })
+                .filter(|repo| {
+                    (!self.args.private || repo.private) && (!self.args.public || !repo.private)
+                })
+                .take(self.args.limit.map_or(usize::MAX, |limit| limit as usize))
+                .collect();
+
+        match format {
+            OutputFormat::Human => Self::print_table(&repos),
+            OutputFormat::Porcelain => Self::print_porcelain(&repos),
+        }
+
+        Ok(())
+    }
+
+    /// Print an aligned table with a count, private repositories in yellow
+    fn print_table(repos: &[RepoSummary]) {
+        let dim = Style::new().dim();
+        let private = Style::new().yellow();
+        let public = Style::new().green();
+
+        if repos.is_empty() {
+            outln!("\nℹ️  No repositories found.");
+            return;
+        }
+
+        outln!();
+        for repo in repos {
+            let style = if repo.private { &private } else { &public };
+            outln!(
+                "  {:<40} {} {}  {}",
+                style.apply_to(&repo.name),
+                style.apply_to(format!("{:<8}", Self::visibility(repo))),
+                dim.apply_to(repo.created_at.get(..10).unwrap_or(&repo.created_at)),
+                Self::truncate(repo.description.as_deref().unwrap_or(""), 60)
+            );
+        }
+
+        outln!("\n📊 {} repositories", repos.len());
+    }
+
+    /// Print one line per repository: name, visibility, created_at, URL, description
+    ///
+    /// This format is part of repogen's interface; new fields are only ever
+    /// appended at the end.
+    fn print_porcelain(repos: &[RepoSummary]) {
+        for repo in repos {
+            output::print_porcelain(&[
+                &repo.name,
+                Self::visibility(repo),
+                &repo.created_at,
+                &repo.html_url,
+                repo.description.as_deref().unwrap_or(""),
+            ]);
+        }
+    }
+
+    /// Shorten `text` to `max` characters, marking the cut with an ellipsis
+    fn truncate(text: &str, max: usize) -> String {
+        if text.chars().count() <= max {
+            return text.to_string();
+        }
+
+        let kept: String = text.chars().take(max - 1).collect();
+        format!("{}…", kept)
+    }
+
+    /// Visibility label shared by both output formats
+    fn visibility(repo: &RepoSummary) -> &'static str {
+        if repo.private { "private" } else { "public" }
+    }
+
+    /// Validate a `YYYY-MM-DD` date, returning it unchanged
+    fn parse_date<'a>(flag: &str, value: &'a str) -> Result<&'a str> {
+        let invalid = || {
+            anyhow!(
+                "Invalid {} '{}': expected a date like 2024-01-31",
+                flag,
+                value
+            )
+        };
+
+        let parts: Vec<&str> = value.split('-').collect();
+        let [year, month, day] = parts[..] else {
+            return Err(invalid());
+        };
+        if year.len() != 4 || month.len() != 2 || day.len() != 2 {
+            return Err(invalid());
+        }
+
+        let year: u32 = year.parse().map_err(|_| invalid())?;
+        let month: u32 = month.parse().map_err(|_| invalid())?;
+        let day: u32 = day.parse().map_err(|_| invalid())?;
+
+        let leap_year =
+            (year.is_multiple_of(4) && !year.is_multiple_of(100)) || year.is_multiple_of(400);
+        let days_in_month = match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if leap_year => 29,
+            2 => 28,
+            _ => return Err(invalid()),
+        };
+
+        if day == 0 || day > days_in_month {
+            return Err(invalid());
+        }
+
+        Ok(value)
+    }
+}
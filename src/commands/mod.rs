@@ -1,8 +1,62 @@
+use crate::cli::Commands;
+use crate::utils::output::Example;
+
+pub mod archive;
+pub mod bootstrap;
+pub mod clone;
 pub mod config;
+pub mod doctor;
 pub mod init;
+pub mod last;
+pub mod list;
 pub mod new;
+pub mod profile;
+pub mod rename;
+pub mod template;
+pub mod transfer;
+pub mod update;
+pub mod whoami;
 
 // Re-export for convenience
-pub use config::{handle_config_clear, handle_config_edit, handle_config_view};
+pub use archive::ArchiveHandler;
+pub use bootstrap::BootstrapHandler;
+pub use clone::CloneHandler;
+pub use config::{
+    handle_config_clear, handle_config_clear_sections, handle_config_doctor_fix,
+    handle_config_edit, handle_config_env, handle_config_export, handle_config_lock,
+    handle_config_set, handle_config_view,
+};
+pub use doctor::DoctorHandler;
 pub use init::InitHandler;
+pub use last::LastHandler;
+pub use list::ListHandler;
 pub use new::NewHandler;
+pub use profile::ProfileHandler;
+pub use rename::RenameHandler;
+pub use template::TemplateHandler;
+pub use transfer::TransferHandler;
+pub use update::UpdateHandler;
+pub use whoami::WhoamiHandler;
+
+/// The examples to print when a subcommand was given `--examples`
+pub fn examples(command: &Commands) -> Option<(&'static str, &'static [Example])> {
+    let (requested, name, examples) = match command {
+        Commands::Init(args) => (args.examples, "init", init::EXAMPLES),
+        Commands::New(args) => (args.examples, "new", new::EXAMPLES),
+        Commands::Config(args) => (args.examples, "config", config::EXAMPLES),
+        Commands::Clone(args) => (args.examples, "clone", clone::EXAMPLES),
+        Commands::Update(args) => (args.examples, "update", update::EXAMPLES),
+        Commands::Transfer(args) => (args.examples, "transfer", transfer::EXAMPLES),
+        Commands::Rename(args) => (args.examples, "rename", rename::EXAMPLES),
+        Commands::Archive(args) => (args.examples, "archive", archive::EXAMPLES),
+        Commands::List(args) => (args.examples, "list", list::EXAMPLES),
+        Commands::Bootstrap(args) => (args.examples, "bootstrap", bootstrap::EXAMPLES),
+        Commands::Profile(args) => (args.examples, "profile", profile::EXAMPLES),
+        Commands::Template(args) => (args.examples, "template", template::EXAMPLES),
+        Commands::Whoami(args) => (args.examples, "whoami", whoami::EXAMPLES),
+        Commands::Last(args) => (args.examples, "last", last::EXAMPLES),
+        Commands::Doctor(args) => (args.examples, "doctor", doctor::EXAMPLES),
+    };
+
+    requested.then_some((name, examples))
+}
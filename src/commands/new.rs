@@ -1,14 +1,149 @@
 use crate::cli;
-use crate::utils::config::Config;
+use crate::commands::{InitHandler, clone};
+use crate::utils::config::{Config, Effective, GITATTRIBUTES_OPTIONS};
+use crate::utils::http::{self, ClientOptions, SendLogged};
+use crate::utils::output::Example;
+use crate::utils::preset::Preset;
+use crate::utils::prompt::{self, Assumed};
+use crate::utils::readme::{self, ReadmeContext};
+use crate::utils::symbols;
+use crate::utils::timing::Timer;
+use crate::utils::{
+    browser, git, gitattributes, github, gitignore, hook, last_repo, license, output, preset,
+    preview,
+};
+use crate::{errln, outln};
 use anyhow::{Context, Result, anyhow};
 use console::Style;
+use dialoguer::{Input, Select, theme::ColorfulTheme};
+use indicatif::{ProgressBar, ProgressStyle};
 use reqwest::blocking::Client;
+use reqwest::{StatusCode, Url};
 use serde::{Deserialize, Serialize};
+use std::env;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Invocations printed by `repogen new --examples`
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        description: "Create a repository with your config defaults",
+        command: "repogen new myapp",
+    },
+    Example {
+        description: "Private Rust project with an MIT license",
+        command: "repogen new myapp --visibility private --license MIT --gitignore Rust",
+    },
+    Example {
+        description: "Fill in the flags from a saved preset",
+        command: "repogen new payments --template-preset backend",
+    },
+    Example {
+        description: "Public repository with a description and topics",
+        command: "repogen new myapp --visibility public -d \"A tiny web server\" -t rust -t http",
+    },
+    Example {
+        description: "Start with a README that has setup and usage sections",
+        command: "repogen new myapp -d \"A tiny web server\" --readme-template detailed",
+    },
+    Example {
+        description: "Combine .gitignore templates detected in the current directory",
+        command: "repogen new myapp --gitignore-from-languages",
+    },
+    Example {
+        description: "Create in an organization and give a team push access",
+        command: "repogen new myapp --group acme/backend",
+    },
+    Example {
+        description: "Skip the license and .gitignore from your config",
+        command: "repogen new myapp --license None --gitignore None",
+    },
+    Example {
+        description: "See the request that would be sent, without creating anything",
+        command: "repogen new myapp --visibility private --dry-run",
+    },
+    Example {
+        description: "Start from your organization's template repository",
+        command: "repogen new myapp --template acme/service-template",
+    },
+    Example {
+        description: "Import history from another host and don't clone",
+        command: "repogen new myapp --import-url https://gitlab.com/me/myapp.git --no-clone",
+    },
+];
+
+/// How long to wait for a new repository's clone URL to become reachable
+const CLONE_READY_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Delay between `git ls-remote` reachability checks
+const CLONE_READY_INTERVAL: Duration = Duration::from_secs(2);
+
+/// How long to follow a source import before giving up
+const IMPORT_TIMEOUT: Duration = Duration::from_secs(30 * 60);
+
+/// Delay between source import status checks
+const IMPORT_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Import statuses that mean the import stopped without completing
+const IMPORT_FAILED_STATUSES: &[&str] = &[
+    "auth_failed",
+    "error",
+    "detection_needs_auth",
+    "detection_found_nothing",
+    "detection_found_multiple",
+];
 
 /// Handles the repository creation workflow
 pub struct NewHandler {
     config: Config,
+    /// Flags as given, before the preset filled any in
+    given: cli::New,
+    preset: Option<Preset>,
     args: cli::New,
+    /// Repository name, which can change if the requested one is taken
+    name: String,
+    settings: Effective,
+    org: Option<String>,
+    team: Option<String>,
+    /// Owner and name of the `--template` repository
+    template: Option<(String, String)>,
+    client: Client,
+    global: cli::GlobalArgs,
+    /// Ask before creating; off when the caller already confirmed
+    confirm: bool,
+}
+
+/// Marks a creation request GitHub rejected as unauthorized, so
+/// `--retry-auth` can sign in again
+#[derive(Debug)]
+struct TokenRejected;
+
+impl fmt::Display for TokenRejected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "GitHub rejected the token")
+    }
+}
+
+impl std::error::Error for TokenRejected {}
+
+/// A `.gitignore` combined from several templates or sources
+struct CombinedGitignore {
+    sources: Vec<String>,
+    content: String,
+}
+
+impl CombinedGitignore {
+    /// Join `(name, content)` sections, each under a header
+    fn new(sections: &[(String, String)]) -> Self {
+        Self {
+            sources: sections.iter().map(|(name, _)| name.clone()).collect(),
+            content: gitignore::combine(sections),
+        }
+    }
 }
 
 /// Request body for GitHub repository creation
@@ -16,16 +151,121 @@ pub struct NewHandler {
 struct CreateRepoRequest {
     name: String,
     description: Option<String>,
-    private: bool,
+    /// Left out for internal repositories, which set `visibility` instead
+    #[serde(skip_serializing_if = "Option::is_none")]
+    private: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visibility: Option<&'static str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     license_template: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     gitignore_template: Option<String>,
     auto_init: bool,
+    #[serde(flatten)]
+    features: RepoFeatures,
 }
 
-/// Response from GitHub repository creation
+/// Repository features to turn off; GitHub enables all of them by default
+#[derive(Debug, Serialize)]
+struct RepoFeatures {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    has_issues: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    has_wiki: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    has_projects: Option<bool>,
+}
+
+impl RepoFeatures {
+    /// Whether any feature is turned off
+    fn any_disabled(&self) -> bool {
+        self.has_issues.is_some() || self.has_wiki.is_some() || self.has_projects.is_some()
+    }
+}
+
+/// Request body for generating a repository from a template
+#[derive(Debug, Serialize)]
+struct GenerateRepoRequest<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    owner: Option<&'a str>,
+    name: &'a str,
+    description: Option<&'a str>,
+    private: bool,
+    /// Copy every branch, not just the default one
+    include_all_branches: bool,
+}
+
+/// Request body for updating a repository's security settings
+#[derive(Debug, Serialize)]
+struct UpdateSecurityRequest {
+    security_and_analysis: SecurityAndAnalysis,
+}
+
+/// Security and analysis features to change
+#[derive(Debug, Serialize)]
+struct SecurityAndAnalysis {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    secret_scanning: Option<SecurityFeature>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    secret_scanning_push_protection: Option<SecurityFeature>,
+}
+
+/// Status of a single security feature
+#[derive(Debug, Serialize)]
+struct SecurityFeature {
+    status: &'static str,
+}
+
+/// Request body for protecting a branch
+#[derive(Debug, Serialize)]
+struct BranchProtectionRequest {
+    /// Always sent; `null` requires no status checks
+    required_status_checks: Option<serde_json::Value>,
+    enforce_admins: bool,
+    required_pull_request_reviews: RequiredReviews,
+    /// Always sent; `null` lets anyone with write access push via pull requests
+    restrictions: Option<serde_json::Value>,
+}
+
+/// Pull request review rules for a protected branch
+#[derive(Debug, Serialize)]
+struct RequiredReviews {
+    dismiss_stale_reviews: bool,
+    require_code_owner_reviews: bool,
+    /// 0-6; 0 still requires a pull request, just no approvals
+    required_approving_review_count: u8,
+}
+
+/// Request body for starting a source import
+#[derive(Debug, Serialize)]
+struct StartImportRequest {
+    vcs_url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vcs: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vcs_username: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vcs_password: Option<String>,
+}
+
+/// Progress of a source import
 #[derive(Debug, Deserialize)]
+struct ImportStatus {
+    status: String,
+    status_text: Option<String>,
+    percent: Option<u32>,
+    commit_count: Option<u64>,
+    failed_step: Option<String>,
+}
+
+/// Request body for granting a team access to a repository
+#[derive(Debug, Serialize)]
+struct TeamAccessRequest {
+    permission: &'static str,
+}
+
+/// Response from GitHub repository creation
+#[derive(Debug, Serialize, Deserialize)]
 struct CreateRepoResponse {
     html_url: String,
     clone_url: String,
@@ -33,271 +273,2067 @@ struct CreateRepoResponse {
     name: String,
     full_name: String,
     private: bool,
+    /// `public`, `private` or `internal`
+    #[serde(default)]
+    visibility: Option<String>,
+    default_branch: String,
+    created_at: String,
+}
+
+/// Payload posted to `creation_webhook_url` after a repository is created
+#[derive(Debug, Serialize)]
+struct CreationWebhookPayload<'a> {
+    /// Human-readable summary, used by Slack-style incoming webhooks
+    text: String,
+    repository: &'a str,
+    url: &'a str,
+    creator: &'a str,
+    private: bool,
+    timestamp: &'a str,
+}
+
+/// How a repository's visibility is shown in summaries
+fn visibility_label(private: bool, internal: bool) -> &'static str {
+    match (private, internal) {
+        (_, true) => "Internal 👥",
+        (true, false) => "Private 🔒",
+        (false, false) => "Public 🌍",
+    }
 }
 
 impl NewHandler {
     /// Create a new NewHandler instance
-    pub fn new(args: cli::New) -> Result<Self> {
-        let config = Config::load().context("Failed to load configuration")?;
+    pub fn new(given: cli::New, global: &cli::GlobalArgs) -> Result<Self> {
+        if given.quiet || given.json {
+            output::set_quiet();
+        }
+        let name = given.name.clone().context("No repository name given")?;
+
+        let mut config = Config::load_or_recover().context("Failed to load configuration")?;
+        config.print_warnings();
+
+        if given.private.is_some() || given.public.is_some() {
+            errln!(
+                "⚠️  --private and --public are deprecated; use --visibility private or --visibility public"
+            );
+        }
+
+        let preset = given
+            .template_preset
+            .as_deref()
+            .map(preset::load)
+            .transpose()?;
+        if let Some(preset) = &preset
+            && !preset.labels.is_empty()
+        {
+            config.set_default_labels(preset.labels.clone());
+        }
+
+        let (args, settings) = Self::resolve(&given, preset.as_ref(), &name, &config, global)?;
+        let client = http::client(&ClientOptions::resolve(global, &config))?;
+        let (org, team) = match &args.group {
+            Some(group) => {
+                let (org, team) = Self::parse_group(group)?;
+                (Some(org), Some(team))
+            }
+            None => (args.org.clone(), args.team.clone()),
+        };
+        let template = args
+            .template
+            .as_deref()
+            .map(Self::parse_template)
+            .transpose()?;
+
+        Ok(Self {
+            config,
+            given,
+            preset,
+            args,
+            name,
+            settings,
+            org,
+            team,
+            template,
+            client,
+            global: global.clone(),
+            confirm: true,
+        })
+    }
+
+    /// Fill in the preset and work out the settings for a repository called `name`
+    ///
+    /// Flags given on the command line win over the preset's. `{name}` in
+    /// descriptions and commit messages becomes `name`.
+    fn resolve(
+        given: &cli::New,
+        preset: Option<&Preset>,
+        name: &str,
+        config: &Config,
+        global: &cli::GlobalArgs,
+    ) -> Result<(cli::New, Effective)> {
+        let mut args = given.clone();
+        args.name = Some(name.to_string());
+        if let Some(preset) = preset {
+            preset.apply(&mut args, name);
+        }
+
+        let settings = config.effective(&args, name, global, |key| env::var_os(key))?;
+        Ok((args, settings))
+    }
+
+    /// Create without asking, for callers that already confirmed
+    pub fn skip_confirmation(&mut self) {
+        self.confirm = false;
+    }
+
+    /// Split a `--group` value into organization and team
+    fn parse_group(group: &str) -> Result<(String, String)> {
+        let invalid = || {
+            anyhow!(
+                "Invalid --group '{}': expected <org>/<team>, e.g. acme/backend",
+                group
+            )
+        };
+
+        let (org, team) = group.split_once('/').ok_or_else(invalid)?;
+        let valid_part = |part: &str| {
+            !part.is_empty() && !part.contains('/') && !part.chars().any(char::is_whitespace)
+        };
+
+        if !valid_part(org) || !valid_part(team) {
+            return Err(invalid());
+        }
+
+        Ok((org.to_string(), team.to_string()))
+    }
 
-        Ok(Self { config, args })
+    /// Split a `--template` value into owner and repository name
+    fn parse_template(template: &str) -> Result<(String, String)> {
+        match template.split_once('/') {
+            Some((owner, repo)) if !owner.is_empty() && !repo.is_empty() && !repo.contains('/') => {
+                Ok((owner.to_string(), repo.to_string()))
+            }
+            _ => Err(anyhow!(
+                "Invalid --template '{}': expected <owner>/<repo>, e.g. acme/service-template",
+                template
+            )),
+        }
     }
 
     /// Create the repository on GitHub
+    ///
+    /// Once the repository exists, later setup steps only warn when they
+    /// fail, since the repository is already usable.
     pub fn create_repository(&mut self) -> Result<()> {
+        let timer = Timer::start();
+
         // Display banner
         self.display_banner();
 
-        // Validate we have a token
-        let token = self.config.github_token.as_ref().ok_or_else(|| {
-            anyhow!("No GitHub token found. Run `repogen init --auth` to authenticate.")
-        })?;
+        if self.settings.internal {
+            if self.org.is_none() {
+                return Err(anyhow!(
+                    "--visibility internal is only available for organization repositories. Pass --org or --group."
+                ));
+            }
+            if self.template.is_some() {
+                return Err(anyhow!(
+                    "--visibility internal can't be combined with --template. Create it as private and change the visibility on GitHub."
+                ));
+            }
+        }
+
+        // Re-runs of provisioning scripts find the repository already there
+        if self.args.if_not_exists
+            && let Some(existing) = self.find_existing()?
+        {
+            return self.report_existing(&existing);
+        }
+
+        // Catch a taken name before anything is fetched or shown; previews
+        // stay offline and leave conflicts to the create request
+        if !self.args.dry_run
+            && !self.args.preview_readme
+            && !self.args.gitignore_preview
+            && let Some(token) = self.settings.token.clone()
+            && !self.ensure_name_available(&token)?
+        {
+            outln!("\nCancelled.");
+            return Ok(());
+        }
+
+        let is_private = self.settings.private;
+        let internal = self.settings.internal;
+        let license = self.settings.license.clone();
+        let gitignore = self.settings.gitignore.clone();
+        let description = self.determine_description();
+        github::validate_topics(&self.args.topic)?;
+
+        // Imports need an empty repository, so no license or .gitignore is generated
+        let (license, gitignore) = if let Some(import_url) = &self.args.import_url {
+            Self::validate_import_url(import_url)?;
+            Self::note_skipped_files(&license, &gitignore, "imports need an empty repository");
+            (None, None)
+        } else if self.template.is_some() {
+            Self::note_skipped_files(&license, &gitignore, "the template provides the files");
+            (None, None)
+        } else if let Some(path) = &self.args.local_init {
+            Self::check_local_dir(path)?;
+            Self::note_skipped_files(
+                &license,
+                &gitignore,
+                "the local directory is pushed to an empty repository",
+            );
+            (None, None)
+        } else {
+            (license, gitignore)
+        };
+
+        // `--gitignore Rust,Node` names several templates; a single one goes to GitHub as before
+        let templates = gitignore
+            .as_deref()
+            .map(gitignore::split_templates)
+            .unwrap_or_default();
+        for template in &templates {
+            self.validate_gitignore(template)?;
+        }
+
+        // A combined .gitignore replaces the single template and is committed after creation
+        let mut sections = match self.args.gitignore_from_languages.as_deref() {
+            Some(languages) => self.language_gitignore_sections(languages)?,
+            None => Vec::new(),
+        };
+        let combine_templates =
+            sections.is_empty() && (templates.len() > 1 || self.args.from_gitignore_url.is_some());
+        if combine_templates {
+            for template in &templates {
+                let content = github::fetch_gitignore_template(
+                    &self.client,
+                    self.settings.token.as_deref(),
+                    &self.settings.api_version,
+                    template,
+                )?;
+                sections.push((template.clone(), content));
+            }
+        }
 
-        // Determine settings (CLI flags override config defaults)
-        let is_private = self.determine_privacy();
-        let license = self.determine_license();
-        let gitignore = self.determine_gitignore();
+        // A custom .gitignore is layered on top of the selected template(s)
+        if let Some(source) = &self.args.from_gitignore_url {
+            sections.push(gitignore::fetch_custom(
+                &self.client,
+                self.settings.token.as_deref(),
+                &self.settings.api_version,
+                source,
+            )?);
+        }
+
+        let combined_gitignore = (!sections.is_empty()).then(|| CombinedGitignore::new(&sections));
+        let gitignore = if combined_gitignore.is_some() {
+            None
+        } else {
+            gitignore
+        };
+        // Several templates still create the repository with the first, so it
+        // has a .gitignore even if committing the combined one fails
+        let created_gitignore = if combine_templates && templates.len() > 1 {
+            templates.first().cloned()
+        } else {
+            gitignore.clone()
+        };
+        if let Some(license) = &license {
+            self.validate_license(license)?;
+        }
+        let gitattributes = self.gitattributes_content()?;
 
         // Display configuration
-        self.display_config(&is_private, &license, &gitignore);
+        self.display_config(
+            &description,
+            &is_private,
+            &license,
+            &gitignore,
+            &combined_gitignore,
+        );
+
+        // Show the .gitignore template, then carry on
+        if self.args.gitignore_preview {
+            match &combined_gitignore {
+                Some(combined) => {
+                    outln!(
+                        "\n👀 .gitignore preview ({}):\n",
+                        combined.sources.join(" + ")
+                    );
+                    preview::print_file(".gitignore", &combined.content);
+                }
+                None => self.preview_gitignore(&gitignore)?,
+            }
+        }
+
+        // Preview the README instead of creating anything
+        if self.args.preview_readme {
+            self.preview_readme(&description, &license);
+            return Ok(());
+        }
+
+        // Generated README content replaces GitHub's after creation
+        let readme_content = match &self.args.readme_template {
+            Some(_) if self.initialize_readme() => Some(self.render_readme(&description, &license)),
+            Some(template) => {
+                outln!(
+                    "\nℹ️  Skipping --readme-template {}: no README is being initialized",
+                    template
+                );
+                None
+            }
+            None => None,
+        };
 
         // Create request body
         let request = CreateRepoRequest {
-            name: self.args.name.clone(),
-            description: self.args.description.clone(),
-            private: is_private,
+            name: self.name.clone(),
+            description,
+            private: (!internal).then_some(is_private),
+            visibility: internal.then_some("internal"),
             license_template: license.clone(),
-            gitignore_template: gitignore.clone(),
-            auto_init: self.args.readme,
+            gitignore_template: created_gitignore,
+            auto_init: self.initialize_readme(),
+            features: self.repo_features(),
         };
 
+        // Show the request instead of sending it
+        if self.args.dry_run {
+            return self.print_dry_run(&request);
+        }
+
+        // Validate we have a token
+        let token = self.settings.token.clone().ok_or_else(|| {
+            anyhow!("No GitHub token found. Run `repogen init --auth` to authenticate.")
+        })?;
+
+        self.check_template(&token)?;
+
+        if !self.confirm_creation() {
+            outln!("\nCancelled.");
+            return Ok(());
+        }
+
         // Make API call
-        println!("\n📦 Creating repository on GitHub...");
-        let response = self.call_github_api(token, &request)?;
+        outln!("\n📦 Creating repository on GitHub...");
+        let (mut response, token) = self.create_with_reauth(token, &request)?;
+        let token = token.as_str();
 
         // Display success
         self.display_success(&response);
+        self.remember(&response);
 
-        // Auto-clone if enabled
-        if self.config.auto_clone {
-            self.clone_repository(&response)?;
+        if self.settings.auto_open {
+            outln!("\n🌐 Opening the repository in your browser...");
+            browser::open(&response.html_url);
         }
 
-        Ok(())
-    }
+        // Push the local directory before anything else touches the repository
+        let pushed = match &self.args.local_init {
+            Some(path) => self.push_local_directory(&mut response, path),
+            None => false,
+        };
 
-    /// Display the banner
-    fn display_banner(&self) {
-        let cyan = Style::new().cyan().bold();
-        println!("\n{}", cyan.apply_to("📦 repogen - Create New Repository"));
-        println!("{}", "━".repeat(50));
-    }
+        // Switch to the template branch before anything is committed to or protects it
+        if let Some(branch) = &self.args.template_branch {
+            self.use_template_branch(token, &mut response, branch);
+        }
 
-    /// Determine if repository should be private
-    fn determine_privacy(&self) -> bool {
-        // --public flag takes precedence (explicit public)
-        if self.args.public == Some(true) {
-            return false;
+        // Rename the initial branch before anything is committed to or protects it;
+        // a pushed directory already chose its branch name
+        if let Some(branch) = &self.settings.default_branch
+            && self.args.local_init.is_none()
+        {
+            self.rename_default_branch(token, &mut response, branch);
         }
 
-        // --private flag takes precedence (explicit private)
-        if self.args.private == Some(true) {
-            return true;
+        // Commit the combined .gitignore before anything clones the repository
+        if let Some(combined) = &combined_gitignore {
+            self.commit_gitignore(token, &response, combined);
         }
 
-        // Fall back to config default
-        self.config.default_private
-    }
+        if let Some(content) = &readme_content {
+            self.commit_readme(token, &response, content);
+        }
 
-    /// Determine license to use
-    fn determine_license(&self) -> Option<String> {
-        // CLI flag overrides config
-        if let Some(license) = &self.args.license {
-            if license.to_lowercase() == "none" {
-                return None;
-            }
-            return Some(license.clone());
+        if let Some(content) = gitattributes {
+            self.commit_gitattributes(token, &response, content);
         }
 
-        // Use config default
-        self.config.default_license.clone()
-    }
+        // Tag the repository with the requested topics
+        if !self.args.topic.is_empty() {
+            self.apply_topics(token, &response);
+        }
 
-    /// Determine gitignore template to use
-    fn determine_gitignore(&self) -> Option<String> {
-        // CLI flag overrides config
-        if let Some(gitignore) = &self.args.gitignore {
-            if gitignore.to_lowercase() == "none" {
-                return None;
-            }
-            return Some(gitignore.clone());
+        // Recreate the usual issue labels
+        if !self.args.no_labels && !self.config.default_labels.is_empty() {
+            self.create_labels(token, &response);
         }
 
-        // Use config default
-        self.config.default_gitignore.clone()
-    }
+        // Template generation can't turn features off, so they're changed afterwards
+        if self.template.is_some() {
+            self.disable_features(token, &response);
+        }
 
-    /// Display the configuration being used
-    fn display_config(
-        &self,
-        is_private: &bool,
-        license: &Option<String>,
-        gitignore: &Option<String>,
-    ) {
-        println!("\n📋 Repository Configuration:");
-        println!("   Name: {}", self.args.name);
+        // Enable security features if requested
+        self.configure_security(token, &response);
 
-        if let Some(desc) = &self.args.description {
-            println!("   Description: {}", desc);
+        // Protect the default branch once its initial commits are in
+        if self.args.protect {
+            self.protect_default_branch(token, &response, self.initialize_readme() || pushed);
         }
 
-        println!(
-            "   Visibility: {}",
-            if *is_private {
-                "Private 🔒"
-            } else {
-                "Public 🌍"
-            }
-        );
+        // Give the team access if creating for an org team
+        self.grant_team_access(token, &response);
 
-        if let Some(lic) = license {
-            println!("   License: {}", lic);
+        // Show how to pin the repository on the profile if requested
+        if self.args.pin_help {
+            self.print_pin_help(&response);
         }
 
-        if let Some(gi) = gitignore {
-            println!("   .gitignore: {}", gi);
+        // Notify the creation webhook if configured
+        self.notify_webhook(&response);
+
+        // Bring in history from the external source before cloning
+        if let Some(import_url) = &self.args.import_url {
+            self.import_source(token, &response, import_url)?;
         }
 
-        println!(
-            "   Initialize with README: {}",
-            if self.args.readme { "Yes" } else { "No" }
+        // Auto-clone if enabled; a pushed directory is already a clone
+        if let Some(path) = &self.args.local_init {
+            if self.settings.auto_clone {
+                outln!(
+                    "\nℹ️  Not cloning: {} is already linked to the repository",
+                    path.display()
+                );
+            }
+        } else if self.settings.auto_clone {
+            if self.args.wait_clone_ready {
+                self.wait_for_clone_ready(self.clone_url(&response))?;
+            }
+            let repo_path = clone::clone_repository(
+                &self.config,
+                self.clone_url(&response),
+                &response.name,
+                self.settings.recurse_submodules,
+            )?;
+            if !self.settings.skip_git_config {
+                self.apply_author_identity(&repo_path);
+                if self.settings.commit_sign {
+                    self.configure_commit_signing(&repo_path);
+                }
+            }
+            if let Some(command) = &self.settings.post_clone_command {
+                Self::run_post_clone(command, &repo_path);
+            }
+        } else {
+            self.note_clone_only_flags();
+        }
+
+        outln!(
+            "\n{}",
+            Style::new()
+                .dim()
+                .apply_to(format!("⏱️  Finished in {}", timer.elapsed()))
         );
+
+        // Script-friendly results go straight to stdout
+        if self.args.json {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&response)
+                    .context("Failed to serialize repository")?
+            );
+        } else if self.args.quiet {
+            println!("{}", response.html_url);
+        }
+
+        Ok(())
     }
 
-    /// Call GitHub API to create repository
-    fn call_github_api(
-        &self,
-        token: &str,
-        request: &CreateRepoRequest,
-    ) -> Result<CreateRepoResponse> {
-        let client = Client::new();
-
-        let response = client
-            .post("https://api.github.com/user/repos")
-            .header("Authorization", format!("Bearer {}", token))
-            .header("User-Agent", "repogen-cli")
-            .header("Accept", "application/vnd.github+json")
-            .header("X-GitHub-Api-Version", "2022-11-28")
-            .json(&request)
-            .send()
-            .context("Failed to send request to GitHub API")?;
+    /// Display the banner
+    fn display_banner(&self) {
+        let cyan = Style::new().cyan().bold();
+        outln!("\n{}", cyan.apply_to("📦 repogen - Create New Repository"));
+        outln!("{}", "━".repeat(50));
+    }
 
-        if !response.status().is_success() {
-            let status = response.status();
-            let error_text = response
-                .text()
-                .unwrap_or_else(|_| "Unknown error".to_string());
+    /// Determine the description to send, sanitizing it if configured
+    fn determine_description(&self) -> Option<String> {
+        if let Some(lang) = &self.args.lang
+            && !self.config.description_templates.contains_key(lang)
+        {
+            outln!(
+                "{}",
+                Style::new().dim().apply_to(format!(
+                    "ℹ️  No description template for '{}', using the default",
+                    lang
+                ))
+            );
+        }
+
+        let description = self.settings.description.clone()?;
 
-            return Err(anyhow!("GitHub API error ({}): {}", status, error_text));
+        if !self.settings.sanitize_description {
+            return Some(description);
         }
 
-        let repo: CreateRepoResponse = response
-            .json()
-            .context("Failed to parse GitHub API response")?;
+        if Self::looks_like_markdown(&description) {
+            outln!(
+                "{}",
+                Style::new().yellow().apply_to(
+                    "⚠️  Description contains markdown, which GitHub shows as plain text"
+                )
+            );
+        }
 
-        Ok(repo)
+        // GitHub strips newlines, so collapse all whitespace runs into single spaces
+        let collapsed = description.split_whitespace().collect::<Vec<_>>().join(" ");
+
+        if collapsed.is_empty() {
+            None
+        } else {
+            Some(collapsed)
+        }
     }
 
-    /// Display success message
-    fn display_success(&self, response: &CreateRepoResponse) {
-        let green = Style::new().green().bold();
-        let cyan = Style::new().cyan();
+    /// Heuristic check for markdown syntax that won't render in a description
+    fn looks_like_markdown(text: &str) -> bool {
+        const INLINE_MARKERS: &[&str] = &["**", "__", "`", "](", "~~"];
+        const LINE_PREFIXES: &[&str] = &["#", "- ", "* ", "> "];
 
-        println!(
-            "\n{}",
-            green.apply_to("✅ Repository created successfully!")
-        );
-        println!("\n{}", cyan.apply_to("📍 Repository Details:"));
-        println!("   Name: {}", response.full_name);
-        println!("   URL: {}", response.html_url);
-        println!(
-            "   Visibility: {}",
-            if response.private {
-                "Private 🔒"
-            } else {
-                "Public 🌍"
-            }
-        );
+        INLINE_MARKERS.iter().any(|marker| text.contains(marker))
+            || text.lines().any(|line| {
+                let line = line.trim_start();
+                LINE_PREFIXES.iter().any(|prefix| line.starts_with(prefix))
+            })
+    }
 
-        println!("\n{}", cyan.apply_to("🔗 Clone URLs:"));
-        println!("   HTTPS: {}", response.clone_url);
-        println!("   SSH:   {}", response.ssh_url);
+    /// Render the README that would be generated and print it
+    fn preview_readme(&self, description: &Option<String>, license: &Option<String>) {
+        if !self.args.readme {
+            outln!("\nℹ️  No README will be generated (--readme is off).");
+            return;
+        }
 
-        println!("\n{}", cyan.apply_to("💡 Next Steps:"));
-        println!("   git clone {}", response.clone_url);
-        println!("   cd {}", response.name);
+        let content = self.render_readme(description, license);
 
-        if let Some(editor) = &self.config.preferred_editor {
-            match editor.as_str() {
-                "VS Code" => println!("   code ."),
-                "Vim" => println!("   vim ."),
-                "Emacs" => println!("   emacs ."),
-                "Sublime Text" => println!("   subl ."),
-                _ => {}
-            }
-        }
+        outln!("\n👀 README preview:\n");
+        preview::print_file("README.md", &content);
+        outln!(
+            "\n{}",
+            Style::new()
+                .dim()
+                .apply_to("(preview only, nothing created)")
+        );
     }
 
-    /// Clone the repository to the configured directory
-    fn clone_repository(&self, response: &CreateRepoResponse) -> Result<()> {
-        use std::process::Command;
-        use std::env;
-        use std::path::PathBuf;
+    /// README content from the chosen --readme-template, `basic` by default
+    fn render_readme(&self, description: &Option<String>, license: &Option<String>) -> String {
+        readme::render(
+            self.args.readme_template.as_deref().unwrap_or("basic"),
+            &ReadmeContext {
+                name: &self.name,
+                description: description.as_deref(),
+                owner: self
+                    .org
+                    .as_deref()
+                    .or(self.config.github_username.as_deref()),
+                license: license.as_deref(),
+            },
+        )
+    }
 
-        let cyan = Style::new().cyan().bold();
-        let green = Style::new().green().bold();
+    /// Replace GitHub's initial README with the rendered template
+    ///
+    /// The README created by `auto_init` already exists, so its SHA is sent
+    /// to update it.
+    fn commit_readme(&self, token: &str, response: &CreateRepoResponse, content: &str) {
+        let api_version = &self.settings.api_version;
+        let result = github::file_sha(
+            &self.client,
+            token,
+            api_version,
+            &response.full_name,
+            "README.md",
+        )
+        .and_then(|sha| {
+            github::put_file(
+                &self.client,
+                token,
+                api_version,
+                &response.full_name,
+                "README.md",
+                content,
+                &self.settings.commit_message,
+                sha.as_deref(),
+            )
+        });
 
-        println!("\n{}", cyan.apply_to("📥 Cloning repository..."));
+        match result {
+            Ok(()) => outln!("\n📝 Committed README.md"),
+            Err(e) => errln!(
+                "\n⚠️  Could not commit README.md: {} (repository was still created)",
+                e
+            ),
+        }
+    }
 
-        // Determine target directory
-        let target_dir = if let Some(ref dir) = self.config.clone_directory {
-            PathBuf::from(dir)
-        } else {
-            env::current_dir().context("Failed to get current directory")?
+    /// The selected .gitattributes template, if there's an initial commit to add it to
+    fn gitattributes_content(&self) -> Result<Option<&'static str>> {
+        let Some(template) = &self.settings.gitattributes else {
+            return Ok(None);
         };
 
-        // Ensure target directory exists
-        if !target_dir.exists() {
-            std::fs::create_dir_all(&target_dir)
-                .context(format!("Failed to create directory: {:?}", target_dir))?;
+        let content = gitattributes::render(template).ok_or_else(|| {
+            anyhow!(
+                "Unknown .gitattributes template '{}'. Valid templates: {}",
+                template,
+                GITATTRIBUTES_OPTIONS[1..].join(", ")
+            )
+        })?;
+
+        if !self.initialize_readme() {
+            outln!(
+                "\n{}",
+                Style::new().dim().apply_to(
+                    "ℹ️  .gitattributes skipped: the repository is created without an initial commit"
+                )
+            );
+            return Ok(None);
         }
 
-        // Run git clone
-        let output = Command::new("git")
-            .arg("clone")
-            .arg(&response.clone_url)
-            .current_dir(&target_dir)
-            .output()
-            .context("Failed to execute git clone. Is git installed?")?;
+        Ok(Some(content))
+    }
+
+    /// Add the .gitattributes file to the new repository
+    fn commit_gitattributes(&self, token: &str, response: &CreateRepoResponse, content: &str) {
+        let result = github::put_file(
+            &self.client,
+            token,
+            &self.settings.api_version,
+            &response.full_name,
+            ".gitattributes",
+            content,
+            &self.settings.commit_message,
+            None,
+        );
 
-        if !output.status.success() {
-            let error_msg = String::from_utf8_lossy(&output.stderr);
-            return Err(anyhow!("Git clone failed: {}", error_msg));
+        match result {
+            Ok(()) => outln!("\n📝 Committed .gitattributes"),
+            Err(e) => errln!(
+                "\n⚠️  Could not commit .gitattributes: {} (repository was still created)",
+                e
+            ),
         }
+    }
 
-        // Determine final repository path
-        let repo_path = target_dir.join(&response.name);
-        let repo_path_str = repo_path.display().to_string();
+    /// Fetch the selected .gitignore template and print it
+    fn preview_gitignore(&self, gitignore: &Option<String>) -> Result<()> {
+        let Some(template) = gitignore else {
+            outln!("\nℹ️  No .gitignore template selected, nothing to preview.");
+            return Ok(());
+        };
 
-        println!(
-            "{} Repository cloned to: {}",
-            green.apply_to("✅"),
-            cyan.apply_to(&repo_path_str)
-        );
+        let content = github::fetch_gitignore_template(
+            &self.client,
+            self.settings.token.as_deref(),
+            &self.settings.api_version,
+            template,
+        )?;
 
-        println!("\n{}", cyan.apply_to("💡 Navigate to your repository:"));
-        println!("   cd {}", repo_path_str);
+        outln!("\n👀 .gitignore preview ({}):\n", template);
+        preview::print_file(".gitignore", &content);
 
         Ok(())
     }
+
+    /// Fetch the .gitignore templates for a project's languages, one section each
+    ///
+    /// With no languages given, they are detected from the current directory.
+    /// Names are matched case-insensitively against GitHub's template list.
+    fn language_gitignore_sections(&self, languages: &[String]) -> Result<Vec<(String, String)>> {
+        let token = self.settings.token.as_deref();
+        let api_version = &self.settings.api_version;
+
+        let requested: Vec<String> = if languages.is_empty() {
+            let dir = env::current_dir().context("Failed to get current directory")?;
+            let detected = gitignore::detect_templates(&dir);
+            if detected.is_empty() {
+                return Err(anyhow!(
+                    "No language files found in {}; name the languages instead, e.g. --gitignore-from-languages rust,node",
+                    dir.display()
+                ));
+            }
+            outln!("\n🔍 Detected languages: {}", detected.join(", "));
+            detected.into_iter().map(str::to_string).collect()
+        } else {
+            languages
+                .iter()
+                .map(|language| language.trim().to_string())
+                .filter(|language| !language.is_empty())
+                .collect()
+        };
+
+        let available = github::list_gitignore_templates(&self.client, token, api_version)?;
+        let mut templates: Vec<String> = Vec::new();
+        for language in &requested {
+            let template = available
+                .iter()
+                .find(|name| name.eq_ignore_ascii_case(language))
+                .ok_or_else(|| anyhow!("No .gitignore template named '{}'", language))?;
+            if !templates.contains(template) {
+                templates.push(template.clone());
+            }
+        }
+
+        let mut sections = Vec::new();
+        for template in &templates {
+            let content =
+                github::fetch_gitignore_template(&self.client, token, api_version, template)?;
+            sections.push((template.clone(), content));
+        }
+
+        Ok(sections)
+    }
+
+    /// Commit a combined .gitignore to the new repository
+    ///
+    /// Replaces any .gitignore GitHub generated.
+    fn commit_gitignore(
+        &self,
+        token: &str,
+        response: &CreateRepoResponse,
+        combined: &CombinedGitignore,
+    ) {
+        let api_version = &self.settings.api_version;
+        let result = github::file_sha(
+            &self.client,
+            token,
+            api_version,
+            &response.full_name,
+            ".gitignore",
+        )
+        .and_then(|sha| {
+            github::put_file(
+                &self.client,
+                token,
+                api_version,
+                &response.full_name,
+                ".gitignore",
+                &combined.content,
+                &self.settings.commit_message,
+                sha.as_deref(),
+            )
+        });
+
+        match result {
+            Ok(()) => outln!(
+                "\n📝 Committed .gitignore ({})",
+                combined.sources.join(" + ")
+            ),
+            Err(e) => errln!(
+                "\n⚠️  Could not commit .gitignore: {} (repository was still created)",
+                e
+            ),
+        }
+    }
+
+    /// Check the .gitignore template against GitHub's list before creating
+    ///
+    /// Skipped when the list can't be fetched; GitHub still rejects unknown
+    /// templates then, just less helpfully.
+    fn validate_gitignore(&self, template: &str) -> Result<()> {
+        match gitignore::available_templates(
+            &self.client,
+            self.settings.token.as_deref(),
+            &self.settings.api_version,
+        ) {
+            Ok(templates) => gitignore::validate_template(&templates, template),
+            Err(_) => Ok(()),
+        }
+    }
+
+    /// Check the license against GitHub's keys before creating
+    ///
+    /// Skipped when the keys can't be fetched, like the .gitignore check.
+    fn validate_license(&self, license: &str) -> Result<()> {
+        match license::available_keys(
+            &self.client,
+            self.settings.token.as_deref(),
+            &self.settings.api_version,
+        ) {
+            Ok(keys) => license::validate(&keys, license),
+            Err(_) => Ok(()),
+        }
+    }
+
+    /// Set the repository topics given with --topic
+    fn apply_topics(&self, token: &str, response: &CreateRepoResponse) {
+        let result = github::replace_topics(
+            &self.client,
+            token,
+            &self.settings.api_version,
+            &response.full_name,
+            &self.args.topic,
+        );
+
+        match result {
+            Ok(topics) => outln!("\n📌 Topics: {}", topics.join(", ")),
+            Err(e) => errln!(
+                "\n⚠️  Could not set topics: {} (repository was still created)",
+                e
+            ),
+        }
+    }
+
+    /// Rename the branch GitHub's initial commit created to `branch`
+    ///
+    /// Skipped when there's no initial commit or the name already matches.
+    fn rename_default_branch(&self, token: &str, response: &mut CreateRepoResponse, branch: &str) {
+        if response.default_branch == branch {
+            return;
+        }
+        if !self.initialize_readme() {
+            outln!(
+                "\nℹ️  Not renaming the default branch to {}: it doesn't exist until something is pushed",
+                branch
+            );
+            return;
+        }
+
+        let url = format!(
+            "{}/repos/{}/branches/{}/rename",
+            github::api_base(),
+            response.full_name,
+            response.default_branch
+        );
+        let result =
+            github::with_headers(self.client.post(&url), token, &self.settings.api_version)
+                .json(&serde_json::json!({ "new_name": branch }))
+                .send_logged();
+
+        match result {
+            Ok(resp) if resp.status().is_success() => {
+                outln!(
+                    "\n🌿 Renamed default branch {} → {}",
+                    response.default_branch,
+                    branch
+                );
+                response.default_branch = branch.to_string();
+            }
+            Ok(resp) => {
+                errln!(
+                    "\n⚠️  Could not rename default branch to {}: {} (repository was still created)",
+                    branch,
+                    github::api_error(resp)
+                );
+            }
+            Err(e) => {
+                errln!(
+                    "\n⚠️  Could not rename default branch to {}: {} (repository was still created)",
+                    branch,
+                    e
+                );
+            }
+        }
+    }
+
+    /// Make a branch copied from the template the default branch
+    fn use_template_branch(&self, token: &str, response: &mut CreateRepoResponse, branch: &str) {
+        if response.default_branch == branch {
+            return;
+        }
+
+        let url = format!("{}/repos/{}", github::api_base(), response.full_name);
+        let result =
+            github::with_headers(self.client.patch(&url), token, &self.settings.api_version)
+                .json(&serde_json::json!({ "default_branch": branch }))
+                .send_logged();
+
+        match result {
+            Ok(resp) if resp.status().is_success() => {
+                outln!("\n🌿 Default branch set to {} from the template", branch);
+                response.default_branch = branch.to_string();
+            }
+            Ok(resp) => {
+                errln!(
+                    "\n⚠️  Could not make {} the default branch: {} (repository was still created)",
+                    branch,
+                    github::api_error(resp)
+                );
+            }
+            Err(e) => {
+                errln!(
+                    "\n⚠️  Could not make {} the default branch: {} (repository was still created)",
+                    branch,
+                    e
+                );
+            }
+        }
+    }
+
+    /// Require reviewed pull requests on the default branch
+    ///
+    /// The branch only exists straight away when GitHub made the initial
+    /// README commit or a local directory was pushed.
+    fn protect_default_branch(&self, token: &str, response: &CreateRepoResponse, has_branch: bool) {
+        if !has_branch {
+            outln!(
+                "\nℹ️  Skipping --protect: the default branch doesn't exist until something is pushed. Protect it later under Settings → Branches."
+            );
+            return;
+        }
+
+        let branch = &response.default_branch;
+        let request = BranchProtectionRequest {
+            required_status_checks: None,
+            enforce_admins: false,
+            required_pull_request_reviews: RequiredReviews {
+                dismiss_stale_reviews: self.settings.dismiss_stale_reviews,
+                require_code_owner_reviews: self.settings.require_code_owner_reviews,
+                required_approving_review_count: self.settings.required_reviews,
+            },
+            restrictions: None,
+        };
+
+        let url = format!(
+            "{}/repos/{}/branches/{}/protection",
+            github::api_base(),
+            response.full_name,
+            branch
+        );
+        let result = github::with_headers(self.client.put(&url), token, &self.settings.api_version)
+            .json(&request)
+            .send_logged();
+
+        match result {
+            Ok(resp) if resp.status().is_success() => {
+                outln!(
+                    "\n🔐 Protected {}: pull requests need {}",
+                    branch,
+                    self.review_rules()
+                );
+            }
+            Ok(resp) if resp.status() == StatusCode::NOT_FOUND => {
+                errln!(
+                    "\n⚠️  Could not protect {}: no such branch (repository was still created)",
+                    branch
+                );
+            }
+            Ok(resp) => {
+                errln!(
+                    "\n⚠️  Could not protect {}: {} (repository was still created)",
+                    branch,
+                    github::api_error(resp)
+                );
+            }
+            Err(e) => {
+                errln!(
+                    "\n⚠️  Could not protect {}: {} (repository was still created)",
+                    branch,
+                    e
+                );
+            }
+        }
+    }
+
+    /// Describe the review rules --protect applies, e.g. "2 approving reviews, one from a code owner"
+    fn review_rules(&self) -> String {
+        let mut rules = vec![match self.settings.required_reviews {
+            0 => "no approving reviews".to_string(),
+            1 => "1 approving review".to_string(),
+            n => format!("{} approving reviews", n),
+        }];
+        if self.settings.require_code_owner_reviews {
+            rules.push("a code owner's review".to_string());
+        }
+        if self.settings.dismiss_stale_reviews {
+            rules.push("stale approvals dismissed".to_string());
+        }
+        rules.join(", ")
+    }
+
+    /// Create the config's default_labels, skipping ones that already exist
+    fn create_labels(&self, token: &str, response: &CreateRepoResponse) {
+        let mut created = 0;
+        let mut existing = 0;
+        for label in &self.config.default_labels {
+            match github::create_label(
+                &self.client,
+                token,
+                &self.settings.api_version,
+                &response.full_name,
+                label,
+            ) {
+                Ok(true) => created += 1,
+                Ok(false) => existing += 1,
+                Err(e) => errln!("\n⚠️  Could not create label '{}': {}", label.name, e),
+            }
+        }
+
+        if existing > 0 {
+            outln!(
+                "\n🏷️  Created {} label(s), {} already existed",
+                created,
+                existing
+            );
+        } else {
+            outln!("\n🏷️  Created {} label(s)", created);
+        }
+    }
+
+    /// The URL to clone from: SSH or HTTPS, as configured
+    fn clone_url<'a>(&self, response: &'a CreateRepoResponse) -> &'a str {
+        if self.settings.clone_ssh {
+            &response.ssh_url
+        } else {
+            &response.clone_url
+        }
+    }
+
+    /// Ask whether to go ahead with the configuration shown
+    ///
+    /// Only asked in an interactive terminal; quiet and JSON runs are
+    /// scripted, so they create straight away.
+    fn confirm_creation(&self) -> bool {
+        if !self.confirm || self.args.quiet || self.args.json || !console::user_attended() {
+            return true;
+        }
+
+        prompt::confirm(
+            &ColorfulTheme::default(),
+            self.global.assume_yes,
+            "Create this repository?",
+            true,
+            Assumed::Yes,
+        )
+    }
+
+    /// Display the configuration being used
+    fn display_config(
+        &self,
+        description: &Option<String>,
+        is_private: &bool,
+        license: &Option<String>,
+        gitignore: &Option<String>,
+        combined_gitignore: &Option<CombinedGitignore>,
+    ) {
+        outln!("\n📋 Repository Configuration:");
+        outln!("   Name: {}", self.name);
+
+        if let Some(org) = &self.org {
+            outln!("   Organization: {}", org);
+        }
+
+        if let Some(team) = &self.team {
+            outln!("   Team: {}", team);
+        }
+
+        if let Some(desc) = description {
+            outln!("   Description: {}", desc);
+        }
+
+        outln!(
+            "   Visibility: {}",
+            visibility_label(*is_private, self.settings.internal)
+        );
+
+        if let Some(lic) = license {
+            outln!("   License: {}", lic);
+        }
+
+        if let Some(gi) = gitignore {
+            outln!("   .gitignore: {}", gi);
+        }
+
+        if let Some(combined) = combined_gitignore {
+            outln!("   .gitignore: {} (combined)", combined.sources.join(" + "));
+        }
+
+        if let Some(template) = &self.settings.gitattributes
+            && self.initialize_readme()
+        {
+            outln!("   .gitattributes: {}", template);
+        }
+
+        if let Some(preset) = &self.args.template_preset {
+            outln!("   Preset: {}", preset);
+        }
+
+        if let Some(template) = &self.args.template {
+            outln!("   Template: {}", template);
+            if let Some(branch) = &self.args.template_branch {
+                outln!("   Template branch: {} (all branches copied)", branch);
+            }
+        }
+
+        if let Some(import_url) = &self.args.import_url {
+            outln!("   Import from: {}", import_url);
+        }
+
+        if !self.args.topic.is_empty() {
+            outln!("   Topics: {}", self.args.topic.join(", "));
+        }
+
+        outln!(
+            "   Initialize with README: {}",
+            if self.initialize_readme() {
+                "Yes"
+            } else {
+                "No"
+            }
+        );
+
+        if let Some(template) = &self.args.readme_template
+            && self.initialize_readme()
+        {
+            outln!("   README template: {}", template);
+        }
+
+        if let Some(branch) = &self.settings.default_branch
+            && self.initialize_readme()
+        {
+            outln!("   Default branch: {}", branch);
+        }
+
+        if self.args.protect {
+            outln!("   Protect default branch: Yes ({})", self.review_rules());
+        }
+
+        let disabled = self.disabled_feature_names();
+        if !disabled.is_empty() {
+            outln!("   Turned off: {}", disabled.join(", "));
+        }
+    }
+
+    /// Whether GitHub should create an initial README commit
+    fn initialize_readme(&self) -> bool {
+        self.args.readme
+            && self.args.import_url.is_none()
+            && self.template.is_none()
+            && self.args.local_init.is_none()
+    }
+
+    /// Check the import source is an HTTP(S) URL GitHub can fetch
+    fn validate_import_url(import_url: &str) -> Result<()> {
+        let url = Url::parse(import_url)
+            .map_err(|e| anyhow!("Invalid --import-url '{}': {}", import_url, e))?;
+
+        if !matches!(url.scheme(), "http" | "https") {
+            return Err(anyhow!(
+                "Invalid --import-url '{}': GitHub can only import over http(s)",
+                import_url
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Mention generated files that are skipped, and why
+    fn note_skipped_files(license: &Option<String>, gitignore: &Option<String>, reason: &str) {
+        let dim = Style::new().dim();
+
+        if license.is_some() || gitignore.is_some() {
+            outln!(
+                "\n{}",
+                dim.apply_to(format!("ℹ️  License and .gitignore skipped: {}", reason))
+            );
+        }
+    }
+
+    /// Endpoint that creates the repository for the user or organization
+    fn create_url(&self) -> String {
+        if let Some((owner, repo)) = &self.template {
+            return format!("{}/repos/{}/{}/generate", github::api_base(), owner, repo);
+        }
+
+        match &self.org {
+            Some(org) => format!("{}/orgs/{}/repos", github::api_base(), org),
+            None => format!("{}/user/repos", github::api_base()),
+        }
+    }
+
+    /// Body to send to [`Self::create_url`]
+    ///
+    /// Generating from a template takes the owner in the body and has no
+    /// license, .gitignore or README options.
+    fn request_body(&self, request: &CreateRepoRequest) -> Result<serde_json::Value> {
+        let body = if self.template.is_some() {
+            serde_json::to_value(GenerateRepoRequest {
+                owner: self
+                    .org
+                    .as_deref()
+                    .or(self.config.github_username.as_deref()),
+                name: &request.name,
+                description: request.description.as_deref(),
+                // Internal visibility was rejected for templates up front
+                private: request.private.unwrap_or(true),
+                include_all_branches: self.args.template_branch.is_some(),
+            })
+        } else {
+            serde_json::to_value(request)
+        };
+
+        body.context("Failed to serialize request")
+    }
+
+    /// Check the `--template` repository exists and is marked as a template
+    fn check_template(&self, token: &str) -> Result<()> {
+        let Some((owner, repo)) = &self.template else {
+            return Ok(());
+        };
+
+        let source =
+            github::find_repo(&self.client, token, &self.settings.api_version, owner, repo)?
+                .ok_or_else(|| anyhow!("Template repository {}/{} not found", owner, repo))?;
+
+        if !source.is_template {
+            return Err(anyhow!(
+                "{} is not a template repository. Mark it as a template in its settings on GitHub first.",
+                source.full_name
+            ));
+        }
+
+        if let Some(branch) = &self.args.template_branch
+            && !github::branch_exists(
+                &self.client,
+                token,
+                &self.settings.api_version,
+                owner,
+                repo,
+                branch,
+            )?
+        {
+            return Err(anyhow!(
+                "Template repository {} has no branch '{}'",
+                source.full_name,
+                branch
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Print the creation request as JSON without sending it
+    fn print_dry_run(&self, request: &CreateRepoRequest) -> Result<()> {
+        let body = serde_json::to_string_pretty(&self.request_body(request)?)
+            .context("Failed to serialize request")?;
+
+        outln!("\n👀 Request that would be sent:\n");
+        outln!("POST {}", self.create_url());
+        // Printed verbatim so the JSON matches what would be sent
+        println!("{}", body);
+        outln!(
+            "\n{}",
+            Style::new().dim().apply_to("(dry run, nothing created)")
+        );
+
+        Ok(())
+    }
+
+    /// Call GitHub API to create repository
+    fn call_github_api(
+        &self,
+        token: &str,
+        request: &CreateRepoRequest,
+    ) -> Result<CreateRepoResponse> {
+        let client = &self.client;
+        let url = self.create_url();
+        let body = self.request_body(request)?;
+        let build = || {
+            github::with_headers(client.post(&url), token, &self.settings.api_version).json(&body)
+        };
+
+        let response = if let Some(policy) = self.settings.retry {
+            github::send_with_pacing(policy, build)?
+        } else {
+            build()
+                .send_logged()
+                .context("Failed to send request to GitHub API")?
+        };
+        github::check_rate_limit(&response)?;
+
+        if !response.status().is_success() {
+            let (status, error_text) = github::read_error_body(response);
+
+            if status == StatusCode::UNPROCESSABLE_ENTITY
+                && error_text
+                    .as_deref()
+                    .is_some_and(|t| t.contains("name already exists"))
+                && let Some(message) = self.describe_name_conflict(client, token)
+            {
+                return Err(anyhow!(message));
+            }
+
+            if status == StatusCode::UNAUTHORIZED && self.args.retry_auth {
+                return Err(anyhow::Error::new(TokenRejected)
+                    .context(github::api_error_from(status, error_text).to_string()));
+            }
+
+            return Err(github::api_error_from(status, error_text));
+        }
+
+        let repo: CreateRepoResponse = response
+            .json()
+            .context("Failed to parse GitHub API response")?;
+
+        Ok(repo)
+    }
+
+    /// Create the repository, signing in again once if `--retry-auth` is set
+    /// and GitHub rejects the token
+    ///
+    /// Returns the token that worked, for the requests that follow.
+    fn create_with_reauth(
+        &mut self,
+        token: String,
+        request: &CreateRepoRequest,
+    ) -> Result<(CreateRepoResponse, String)> {
+        match self.call_github_api(&token, request) {
+            Err(e) if e.downcast_ref::<TokenRejected>().is_some() => {
+                errln!("\n⚠️  {}", e);
+                let retry = prompt::confirm(
+                    &ColorfulTheme::default(),
+                    self.global.assume_yes,
+                    "Your GitHub token was rejected. Sign in again and retry?",
+                    true,
+                    Assumed::No,
+                );
+                if !retry {
+                    return Err(e);
+                }
+
+                let token = InitHandler::refresh_token(&self.global)?;
+                self.settings.token = Some(token.clone());
+
+                outln!("\n📦 Retrying repository creation...");
+                let response = self.call_github_api(&token, request)?;
+                Ok((response, token))
+            }
+            result => result.map(|response| (response, token)),
+        }
+    }
+
+    /// Look up the repository `--if-not-exists` asks about
+    fn find_existing(&self) -> Result<Option<CreateRepoResponse>> {
+        let token = self.settings.token.as_deref().ok_or_else(|| {
+            anyhow!("No GitHub token found. Run `repogen init --auth` to authenticate.")
+        })?;
+        let owner = self
+            .org
+            .as_deref()
+            .or(self.config.github_username.as_deref())
+            .ok_or_else(|| {
+                anyhow!(
+                    "--if-not-exists needs to know the owner. Pass --org or run `repogen init --meta`."
+                )
+            })?;
+
+        github::get_repo(
+            &self.client,
+            token,
+            &self.settings.api_version,
+            owner,
+            &self.name,
+        )
+    }
+
+    /// Record the new repository for `repogen last` and a bare `repogen clone`
+    fn remember(&self, response: &CreateRepoResponse) {
+        let last = last_repo::LastRepo {
+            full_name: response.full_name.clone(),
+            html_url: response.html_url.clone(),
+            created_at: response.created_at.clone(),
+        };
+        if let Err(e) = last_repo::save(&last) {
+            errln!(
+                "⚠️  Could not record the new repository for `repogen last`: {}",
+                e
+            );
+        }
+    }
+
+    /// Print a repository that already exists, in the same shapes as a new one
+    fn report_existing(&self, existing: &CreateRepoResponse) -> Result<()> {
+        outln!(
+            "\nℹ️  {} already exists, nothing was created (--if-not-exists)",
+            existing.full_name
+        );
+        outln!("   URL: {}", existing.html_url);
+
+        if self.args.json {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(existing).context("Failed to serialize repository")?
+            );
+        } else if self.args.quiet {
+            println!("{}", existing.html_url);
+        }
+
+        Ok(())
+    }
+
+    /// Make sure the requested name is free before creating anything
+    ///
+    /// In an interactive terminal a taken name can be replaced with another
+    /// one; otherwise (including under `--assume-yes`) it's an error. Returns
+    /// `false` when the user chooses to abort. Lookup failures are ignored
+    /// here, since the create request reports conflicts too.
+    fn ensure_name_available(&mut self, token: &str) -> Result<bool> {
+        let interactive = console::user_attended()
+            && !self.global.assume_yes
+            && !self.args.quiet
+            && !self.args.json;
+
+        let mut renamed = false;
+        while let Some(message) = self.describe_name_conflict(&self.client, token) {
+            if !interactive {
+                return Err(anyhow!(message));
+            }
+
+            errln!("\n⚠️  {}", message);
+            let theme = ColorfulTheme::default();
+            let choice = Select::with_theme(&theme)
+                .with_prompt("What would you like to do?")
+                .items(["Choose a different name", "Abort"])
+                .default(0)
+                .interact()
+                .unwrap();
+            if choice == 1 {
+                return Ok(false);
+            }
+
+            let taken = self.name.clone();
+            self.name = Input::with_theme(&theme)
+                .with_prompt("Repository name")
+                .validate_with(|input: &String| {
+                    github::validate_repo_name(input)?;
+                    if input.eq_ignore_ascii_case(&taken) {
+                        return Err("That name is taken".to_string());
+                    }
+                    Ok(())
+                })
+                .interact_text()
+                .unwrap();
+            renamed = true;
+        }
+
+        // The preset and templates filled `{name}` in with the old name
+        if renamed {
+            (self.args, self.settings) = Self::resolve(
+                &self.given,
+                self.preset.as_ref(),
+                &self.name,
+                &self.config,
+                &self.global,
+            )?;
+        }
+
+        Ok(true)
+    }
+
+    /// Explain a name conflict, pointing out differences in casing
+    ///
+    /// GitHub treats repository names case-insensitively, so creating `MyRepo`
+    /// fails when `myrepo` exists. Returns `None` if the name is free or the
+    /// existing repository can't be looked up.
+    fn describe_name_conflict(&self, client: &Client, token: &str) -> Option<String> {
+        let owner = self
+            .org
+            .as_deref()
+            .or(self.config.github_username.as_deref())?;
+        let existing =
+            github::find_repo(client, token, &self.settings.api_version, owner, &self.name)
+                .ok()??;
+
+        if existing.name == self.name {
+            Some(format!(
+                "A repository named '{}' already exists on {}",
+                existing.name, owner
+            ))
+        } else {
+            Some(format!(
+                "A repository named '{}' already exists on {} (you requested '{}'; GitHub names are case-insensitive)",
+                existing.name, owner, self.name
+            ))
+        }
+    }
+
+    /// Display success message
+    fn display_success(&self, response: &CreateRepoResponse) {
+        let green = Style::new().green().bold();
+        let cyan = Style::new().cyan();
+
+        outln!(
+            "\n{}",
+            green.apply_to("✅ Repository created successfully!")
+        );
+        outln!("\n{}", cyan.apply_to("📍 Repository Details:"));
+        outln!("   Name: {}", response.full_name);
+        outln!("   URL: {}", response.html_url);
+        outln!(
+            "   Visibility: {}",
+            visibility_label(
+                response.private,
+                response.visibility.as_deref() == Some("internal")
+            )
+        );
+
+        outln!("\n{}", cyan.apply_to("🔗 Clone URLs:"));
+        outln!("   HTTPS: {}", response.clone_url);
+        outln!("   SSH:   {}", response.ssh_url);
+
+        outln!("\n{}", cyan.apply_to("💡 Next Steps:"));
+        outln!("   git clone {}", self.clone_url(response));
+        outln!("   cd {}", response.name);
+
+        if let Some(editor) = &self.config.preferred_editor {
+            match editor.as_str() {
+                "VS Code" => outln!("   code ."),
+                "Vim" => outln!("   vim ."),
+                "Emacs" => outln!("   emacs ."),
+                "Sublime Text" => outln!("   subl ."),
+                _ => {}
+            }
+        }
+    }
+
+    /// The features this repository is created without
+    fn repo_features(&self) -> RepoFeatures {
+        RepoFeatures {
+            has_issues: self.settings.disable_issues.then_some(false),
+            has_wiki: self.settings.disable_wiki.then_some(false),
+            has_projects: self.settings.disable_projects.then_some(false),
+        }
+    }
+
+    /// Turn off the requested features on a repository generated from a template
+    ///
+    /// The repository already exists at this point, so failures are warnings.
+    fn disable_features(&self, token: &str, response: &CreateRepoResponse) {
+        let features = self.repo_features();
+        if !features.any_disabled() {
+            return;
+        }
+
+        let url = format!("{}/repos/{}", github::api_base(), response.full_name);
+        let result =
+            github::with_headers(self.client.patch(&url), token, &self.settings.api_version)
+                .json(&features)
+                .send_logged();
+
+        match result {
+            Ok(resp) if resp.status().is_success() => {
+                outln!(
+                    "\n✅ Turned off {}",
+                    self.disabled_feature_names().join(", ")
+                );
+            }
+            Ok(resp) => errln!(
+                "\n⚠️  Failed to turn off repository features: {}",
+                github::api_error(resp)
+            ),
+            Err(e) => errln!("\n⚠️  Failed to turn off repository features: {}", e),
+        }
+    }
+
+    /// Names of the features turned off, for messages
+    fn disabled_feature_names(&self) -> Vec<&'static str> {
+        [
+            (self.settings.disable_issues, "issues"),
+            (self.settings.disable_wiki, "wiki"),
+            (self.settings.disable_projects, "projects"),
+        ]
+        .into_iter()
+        .filter_map(|(disabled, name)| disabled.then_some(name))
+        .collect()
+    }
+
+    /// Enable secret scanning and push protection if requested
+    ///
+    /// These features depend on the account's plan, so failures are reported
+    /// as warnings rather than failing the whole run.
+    fn configure_security(&self, token: &str, response: &CreateRepoResponse) {
+        let secret_scanning = self.settings.enable_secret_scanning;
+        let push_protection = self.settings.enable_push_protection;
+
+        if !secret_scanning && !push_protection {
+            return;
+        }
+
+        let enabled = || Some(SecurityFeature { status: "enabled" });
+        let request = UpdateSecurityRequest {
+            security_and_analysis: SecurityAndAnalysis {
+                secret_scanning: if secret_scanning { enabled() } else { None },
+                secret_scanning_push_protection: if push_protection { enabled() } else { None },
+            },
+        };
+
+        outln!("\n🛡️  Enabling secret scanning features...");
+
+        let url = format!("{}/repos/{}", github::api_base(), response.full_name);
+        let result =
+            github::with_headers(self.client.patch(&url), token, &self.settings.api_version)
+                .json(&request)
+                .send_logged();
+
+        match result {
+            Ok(resp) if resp.status().is_success() => {
+                if secret_scanning {
+                    outln!("   ✅ Secret scanning enabled");
+                }
+                if push_protection {
+                    outln!("   ✅ Push protection enabled");
+                }
+            }
+            Ok(resp)
+                if matches!(
+                    resp.status(),
+                    StatusCode::FORBIDDEN | StatusCode::UNPROCESSABLE_ENTITY
+                ) =>
+            {
+                outln!(
+                    "   ⚠️  Secret scanning isn't available for this repository on your account's plan ({})",
+                    resp.status()
+                );
+            }
+            Ok(resp) => {
+                errln!(
+                    "   ⚠️  Failed to enable secret scanning: {}",
+                    github::api_error(resp)
+                );
+            }
+            Err(e) => {
+                errln!("   ⚠️  Failed to enable secret scanning: {}", e);
+            }
+        }
+    }
+
+    /// Grant the requested team push access to the new repository
+    ///
+    /// The repository already exists at this point, so failures are warnings.
+    fn grant_team_access(&self, token: &str, response: &CreateRepoResponse) {
+        let (Some(org), Some(team)) = (&self.org, &self.team) else {
+            return;
+        };
+
+        let url = format!(
+            "{}/orgs/{}/teams/{}/repos/{}",
+            github::api_base(),
+            org,
+            team,
+            response.full_name
+        );
+        let result = github::with_headers(self.client.put(&url), token, &self.settings.api_version)
+            .json(&TeamAccessRequest { permission: "push" })
+            .send_logged();
+
+        match result {
+            Ok(resp) if resp.status().is_success() => {
+                outln!("\n👥 Granted {}/{} push access", org, team);
+            }
+            Ok(resp) => {
+                errln!(
+                    "\n⚠️  Could not grant {}/{} access: {}",
+                    org,
+                    team,
+                    github::api_error(resp)
+                );
+            }
+            Err(e) => {
+                errln!("\n⚠️  Could not grant {}/{} access: {}", org, team, e);
+            }
+        }
+    }
+
+    /// Print the steps to pin the repository on the user's profile
+    ///
+    /// GitHub has no API for profile pins, so this can only point the way.
+    fn print_pin_help(&self, response: &CreateRepoResponse) {
+        let profile_url = match &self.config.github_username {
+            Some(username) => format!("{}/{}", github::web_base(), username),
+            None => "your GitHub profile".to_string(),
+        };
+
+        outln!("\n📌 To pin {} on your profile:", response.full_name);
+        outln!("   1. Open {}", profile_url);
+        outln!(
+            "   2. Click \"Customize your pins\" and select {}",
+            response.name
+        );
+        outln!("   Only six repositories can be pinned; unpin one first if all slots are taken.");
+    }
+
+    /// POST a creation notice to the configured webhook
+    ///
+    /// Best-effort: failures are reported but never fail the creation.
+    fn notify_webhook(&self, response: &CreateRepoResponse) {
+        let Some(url) = &self.config.creation_webhook_url else {
+            return;
+        };
+
+        let creator = self.config.github_username.as_deref().unwrap_or_else(|| {
+            response
+                .full_name
+                .split_once('/')
+                .map_or(response.full_name.as_str(), |(owner, _)| owner)
+        });
+
+        let payload = CreationWebhookPayload {
+            text: format!(
+                "{} created repository {} ({})",
+                creator, response.full_name, response.html_url
+            ),
+            repository: &response.full_name,
+            url: &response.html_url,
+            creator,
+            private: response.private,
+            timestamp: &response.created_at,
+        };
+
+        let result = self.client.post(url).json(&payload).send_logged();
+
+        match result {
+            Ok(resp) if resp.status().is_success() => {
+                outln!("\n🔔 Creation webhook notified");
+            }
+            Ok(resp) => {
+                errln!(
+                    "\n⚠️  Creation webhook returned {} (repository was still created)",
+                    resp.status()
+                );
+            }
+            Err(e) => {
+                errln!(
+                    "\n⚠️  Could not reach creation webhook: {} (repository was still created)",
+                    e
+                );
+            }
+        }
+    }
+
+    /// Start a source import into the new repository and follow it to completion
+    fn import_source(
+        &self,
+        token: &str,
+        response: &CreateRepoResponse,
+        import_url: &str,
+    ) -> Result<()> {
+        let client = &self.client;
+        let url = format!("{}/repos/{}/import", github::api_base(), response.full_name);
+        let request = StartImportRequest {
+            vcs_url: import_url.to_string(),
+            vcs: self.args.import_vcs.clone(),
+            vcs_username: self.args.import_user.clone(),
+            vcs_password: self.args.import_password.clone(),
+        };
+
+        outln!("\n📥 Starting import from {}...", import_url);
+
+        let start = github::with_headers(client.put(&url), token, &self.settings.api_version)
+            .json(&request)
+            .send_logged()
+            .context("Failed to send request to GitHub API")?;
+
+        if !start.status().is_success() {
+            return Err(anyhow!(
+                "Failed to start import: {}. The empty repository {} was left in place.",
+                github::api_error(start),
+                response.full_name
+            ));
+        }
+
+        let pb = ProgressBar::new_spinner();
+        pb.set_style(
+            ProgressStyle::default_spinner()
+                .tick_chars(symbols::spinner_ticks())
+                .template("{spinner:.cyan} {msg}")
+                .unwrap(),
+        );
+        pb.set_message("Waiting for import to start...");
+        pb.enable_steady_tick(Duration::from_millis(120));
+
+        let started = Instant::now();
+
+        loop {
+            let progress: ImportStatus =
+                github::with_headers(client.get(&url), token, &self.settings.api_version)
+                    .send_logged()
+                    .context("Failed to check import status")?
+                    .error_for_status()
+                    .context("Failed to check import status")?
+                    .json()
+                    .context("Failed to parse import status")?;
+
+            let status_text = progress
+                .status_text
+                .clone()
+                .unwrap_or_else(|| progress.status.clone());
+
+            if progress.status == "complete" {
+                let message = match progress.commit_count {
+                    Some(count) => format!("✅ Import complete ({} commits)", count),
+                    None => "✅ Import complete".to_string(),
+                };
+                pb.finish_with_message(symbols::apply(&message).into_owned());
+                return Ok(());
+            }
+
+            if IMPORT_FAILED_STATUSES.contains(&progress.status.as_str()) {
+                pb.finish_with_message(symbols::apply("❌ Import failed").into_owned());
+
+                let hint = if progress.status.contains("auth") {
+                    " Pass --import-user and --import-password for private sources."
+                } else {
+                    ""
+                };
+                let step = progress
+                    .failed_step
+                    .map(|step| format!(" during {}", step))
+                    .unwrap_or_default();
+
+                return Err(anyhow!(
+                    "Import failed{}: {}.{} The empty repository {} was left in place.",
+                    step,
+                    status_text,
+                    hint,
+                    response.full_name
+                ));
+            }
+
+            match progress.percent {
+                Some(percent) => pb.set_message(format!("{} ({}%)", status_text, percent)),
+                None => pb.set_message(status_text),
+            }
+
+            if started.elapsed() >= IMPORT_TIMEOUT {
+                pb.finish_with_message(symbols::apply("⏳ Import still running").into_owned());
+                return Err(anyhow!(
+                    "Import is still running after {} minutes. Check progress at {}/import",
+                    IMPORT_TIMEOUT.as_secs() / 60,
+                    response.html_url
+                ));
+            }
+
+            thread::sleep(IMPORT_POLL_INTERVAL);
+        }
+    }
+
+    /// Run the post-clone command inside the fresh clone
+    ///
+    /// A failing command is reported as a warning since the repository and
+    /// the clone are both fine.
+    fn run_post_clone(command: &str, repo_path: &Path) {
+        outln!("\n🪝 Running post-clone command: {}", command);
+        match hook::run(command, repo_path) {
+            Ok(()) => outln!("✅ Post-clone command finished"),
+            Err(e) => errln!("⚠️  Post-clone command failed: {}", e),
+        }
+    }
+
+    /// Mention clone-only flags that have no effect without auto-clone
+    fn note_clone_only_flags(&self) {
+        let dim = Style::new().dim();
+        let clone_only_flags = [
+            (self.args.wait_clone_ready, "--wait-clone-ready"),
+            (self.args.recurse_submodules, "--recurse-submodules"),
+            (self.args.no_git_config, "--no-git-config"),
+            (self.args.author_name.is_some(), "--author-name"),
+            (self.args.author_email.is_some(), "--author-email"),
+            (self.args.commit_sign, "--commit-sign"),
+            (self.args.post_clone.is_some(), "--post-clone"),
+        ];
+
+        for (_, flag) in clone_only_flags.iter().filter(|(given, _)| *given) {
+            outln!(
+                "\n{}",
+                dim.apply_to(format!("ℹ️  {} ignored: auto-clone is disabled", flag))
+            );
+        }
+    }
+
+    /// Check a --local-init directory can be pushed, before anything is created
+    fn check_local_dir(path: &Path) -> Result<()> {
+        if !path.is_dir() {
+            return Err(anyhow!(
+                "--local-init {} is not a directory",
+                path.display()
+            ));
+        }
+
+        if !path.join(".git").exists() {
+            let mut entries =
+                fs::read_dir(path).with_context(|| format!("Failed to read {}", path.display()))?;
+            if entries.next().is_none() {
+                return Err(anyhow!(
+                    "{} is empty, so there's nothing to push",
+                    path.display()
+                ));
+            }
+            return Ok(());
+        }
+
+        if git::has_remote(path, "origin") {
+            return Err(anyhow!(
+                "{} already has an 'origin' remote; remove it or push there instead",
+                path.display()
+            ));
+        }
+
+        if !git::has_commits(path) && !git::has_changes(path)? {
+            return Err(anyhow!(
+                "{} has no commits and nothing to add",
+                path.display()
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Push a local directory to the new repository, returning whether it worked
+    ///
+    /// On success the response's default branch becomes the pushed branch.
+    fn push_local_directory(&self, response: &mut CreateRepoResponse, path: &Path) -> bool {
+        outln!("\n📤 Pushing {} to the new repository...", path.display());
+
+        match self.push_local(response, path) {
+            Ok(branch) => {
+                outln!("✅ Pushed {} and set it to track origin/{}", branch, branch);
+                response.default_branch = branch;
+                true
+            }
+            Err(e) => {
+                errln!(
+                    "⚠️  Could not push {}: {} (repository was still created)",
+                    path.display(),
+                    e
+                );
+                errln!("💡 Finish from that directory with: git push -u origin <branch>");
+                false
+            }
+        }
+    }
+
+    /// Initialize and commit the directory as needed, then push its branch
+    ///
+    /// A directory without commits gets one commit of everything in it, on
+    /// the configured default branch. An existing history is pushed as it is,
+    /// from the checked-out branch.
+    fn push_local(&self, response: &CreateRepoResponse, path: &Path) -> Result<String> {
+        if !path.join(".git").exists() {
+            git::init(path)?;
+            outln!("   Initialized a git repository");
+            if !self.settings.skip_git_config {
+                self.apply_author_identity(path);
+            }
+        }
+
+        let branch = if git::has_commits(path) {
+            if git::has_changes(path)? {
+                outln!("   ℹ️  Uncommitted changes stay local; only existing commits are pushed");
+            }
+            git::current_branch(path)?
+        } else {
+            git::commit_all(path, &self.settings.commit_message)?;
+            let branch = self
+                .settings
+                .default_branch
+                .clone()
+                .unwrap_or_else(|| response.default_branch.clone());
+            git::rename_branch(path, &branch)?;
+            outln!("   Committed all files on {}", branch);
+            branch
+        };
+
+        git::add_remote(path, "origin", self.clone_url(response))?;
+        git::push_upstream(path, "origin", &branch)?;
+
+        Ok(branch)
+    }
+
+    /// Set the commit identity in the cloned repository
+    ///
+    /// `--author-name`/`--author-email` win over the profile's identity, which
+    /// is only used with `set_local_git_identity`.
+    fn apply_author_identity(&self, repo_path: &Path) {
+        let (name, email) = self.config.local_git_identity();
+        clone::set_identity(
+            repo_path,
+            self.args.author_name.as_deref().or(name),
+            self.args.author_email.as_deref().or(email),
+        );
+    }
+
+    /// Turn on commit signing in the cloned repository
+    ///
+    /// Uses `signing_key` from config when set; otherwise git falls back to a
+    /// globally configured `user.signingkey`, and we warn if there is none.
+    fn configure_commit_signing(&self, repo_path: &Path) {
+        if let Err(e) = git::set_config(repo_path, "commit.gpgsign", "true") {
+            errln!("⚠️  Could not enable commit signing: {}", e);
+            return;
+        }
+        outln!("🔐 Enabled commit signing");
+
+        match &self.config.signing_key {
+            Some(key) => match git::set_config(repo_path, "user.signingkey", key) {
+                Ok(()) => outln!("🔐 Set user.signingkey to {}", key),
+                Err(e) => errln!("⚠️  Could not set user.signingkey: {}", e),
+            },
+            None if git::get_config(repo_path, "user.signingkey").is_none() => {
+                errln!(
+                    "⚠️  No signing key configured: set signing_key in your config or user.signingkey in git, or commits will fail to sign"
+                );
+            }
+            None => {}
+        }
+    }
+
+    /// Poll the clone URL with `git ls-remote` until GitHub serves it
+    ///
+    /// Freshly created repositories can briefly 404 on the git endpoint even
+    /// though the API already reports them, so retry until reachable or timed out.
+    fn wait_for_clone_ready(&self, clone_url: &str) -> Result<()> {
+        let pb = ProgressBar::new_spinner();
+        pb.set_style(
+            ProgressStyle::default_spinner()
+                .tick_chars(symbols::spinner_ticks())
+                .template("{spinner:.cyan} {msg}")
+                .unwrap(),
+        );
+        pb.set_message("Waiting for clone URL to become reachable...");
+        pb.enable_steady_tick(Duration::from_millis(120));
+
+        let started = Instant::now();
+
+        loop {
+            let status = Command::new("git")
+                .arg("ls-remote")
+                .arg(clone_url)
+                // Never block on a credential prompt while polling
+                .env("GIT_TERMINAL_PROMPT", "0")
+                .output()
+                .context("Failed to execute git ls-remote. Is git installed?")?
+                .status;
+
+            if status.success() {
+                pb.finish_with_message(symbols::apply("✅ Clone URL is reachable").into_owned());
+                return Ok(());
+            }
+
+            if started.elapsed() >= CLONE_READY_TIMEOUT {
+                pb.finish_with_message(symbols::apply("❌ Clone URL not reachable").into_owned());
+                return Err(anyhow!(
+                    "Clone URL {} was not reachable after {}s",
+                    clone_url,
+                    CLONE_READY_TIMEOUT.as_secs()
+                ));
+            }
+
+            thread::sleep(CLONE_READY_INTERVAL);
+        }
+    }
 }
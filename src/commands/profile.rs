@@ -0,0 +1,97 @@
+use crate::cli;
+use crate::outln;
+use crate::utils::config::{self, Config};
+use crate::utils::output::Example;
+use anyhow::Result;
+use console::style;
+
+/// Invocations printed by `repogen profile --examples`
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        description: "List profiles and see which one is active",
+        command: "repogen profile list",
+    },
+    Example {
+        description: "Use the work profile from now on",
+        command: "repogen profile switch work",
+    },
+    Example {
+        description: "Set up a new profile",
+        command: "repogen --profile personal init",
+    },
+    Example {
+        description: "Use another profile for a single command",
+        command: "repogen --profile personal new dotfiles --visibility private",
+    },
+];
+
+/// Lists config profiles and switches between them
+pub struct ProfileHandler {
+    args: cli::Profile,
+}
+
+impl ProfileHandler {
+    /// Create a new ProfileHandler instance
+    pub fn new(args: cli::Profile) -> Self {
+        Self { args }
+    }
+
+    /// Run the requested profile subcommand, listing profiles by default
+    pub fn run(&self) -> Result<()> {
+        match &self.args.command {
+            Some(cli::ProfileCommand::Switch { name }) => self.switch(name),
+            Some(cli::ProfileCommand::List) | None => self.list(),
+        }
+    }
+
+    /// Show every profile with its GitHub user, marking the active one
+    fn list(&self) -> Result<()> {
+        let active = config::active_profile();
+        let mut profiles = Config::list_profiles()?;
+        if !profiles.iter().any(|profile| profile == active) {
+            profiles.push(active.to_string());
+            profiles.sort();
+        }
+
+        outln!("\n{}", style("👤 Profiles").cyan().bold());
+        outln!("{}", style("━".repeat(50)).dim());
+
+        for profile in &profiles {
+            let username = Config::read_profile(profile)
+                .ok()
+                .and_then(|config| config.github_username);
+            let detail = match username {
+                Some(username) => style(format!("@{}", username)).dim(),
+                None => style("not set up".to_string()).dim(),
+            };
+
+            if profile == active {
+                outln!(
+                    "  {} {} {}",
+                    style("*").green(),
+                    style(profile).green(),
+                    detail
+                );
+            } else {
+                outln!("    {} {}", profile, detail);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Save `name` as the profile used when `--profile` isn't given
+    fn switch(&self, name: &str) -> Result<()> {
+        Config::save_active_profile(name)?;
+        outln!("✅ Switched to profile '{}'", name);
+
+        if !Config::profile_file(name)?.exists() {
+            outln!(
+                "💡 This profile isn't set up yet. Run {} to configure it",
+                style("repogen init").cyan()
+            );
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,150 @@
+use crate::cli;
+use crate::outln;
+use crate::utils::config::Config;
+use crate::utils::github;
+use crate::utils::http::{self, ClientOptions, SendLogged};
+use crate::utils::output::Example;
+use anyhow::{Context, Result, anyhow};
+use console::{Style, style};
+use reqwest::StatusCode;
+use reqwest::blocking::Client;
+use serde::{Deserialize, Serialize};
+
+/// Invocations printed by `repogen rename --examples`
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        description: "Give one of your repositories a better name",
+        command: "repogen rename myapp myapp-api",
+    },
+    Example {
+        description: "Rename a repository in an organization",
+        command: "repogen rename acme/old-service new-service",
+    },
+];
+
+/// Handles renaming an existing repository
+pub struct RenameHandler {
+    config: Config,
+    name: String,
+    new_name: String,
+    api_version: String,
+    token: Option<String>,
+    client: Client,
+}
+
+/// Request body for a repository rename
+#[derive(Debug, Serialize)]
+struct RenameRequest<'a> {
+    name: &'a str,
+}
+
+/// The parts of the renamed repository repogen reports
+#[derive(Debug, Deserialize)]
+struct RenameResponse {
+    full_name: String,
+    html_url: String,
+    clone_url: String,
+    ssh_url: String,
+}
+
+impl RenameHandler {
+    /// Create a new RenameHandler instance
+    pub fn new(args: cli::Rename, global: &cli::GlobalArgs) -> Result<Self> {
+        let config = Config::load_or_recover().context("Failed to load configuration")?;
+        config.print_warnings();
+        let api_version = global
+            .api_version
+            .clone()
+            .unwrap_or_else(|| config.api_version().to_string());
+        let token = config.resolve_token(global.token_file.as_deref())?;
+        let client = http::client(&ClientOptions::resolve(global, &config))?;
+
+        let name = args.name.context("No repository given")?;
+        let new_name = args.new_name.context("No new name given")?;
+
+        Ok(Self {
+            config,
+            name,
+            new_name,
+            api_version,
+            token,
+            client,
+        })
+    }
+
+    /// Rename the repository and point out what needs updating locally
+    pub fn run(&self) -> Result<()> {
+        let token = self.token.as_ref().ok_or_else(|| {
+            anyhow!("No GitHub token found. Run `repogen init --auth` to authenticate.")
+        })?;
+        let (owner, repo) = github::split_repo(&self.name, self.config.github_username.as_deref())?;
+        let new_name = self.new_name.trim();
+
+        github::validate_repo_name(new_name)
+            .map_err(|e| anyhow!("Invalid new name '{}': {}", new_name, e))?;
+        if new_name == repo {
+            return Err(anyhow!("{}/{} already has that name", owner, repo));
+        }
+
+        outln!(
+            "\n{}",
+            style(format!("✏️  Rename {}/{} to {}", owner, repo, new_name))
+                .cyan()
+                .bold()
+        );
+
+        let url = format!("{}/repos/{}/{}", github::api_base(), owner, repo);
+        let response = github::with_headers(self.client.patch(&url), token, &self.api_version)
+            .json(&RenameRequest { name: new_name })
+            .send_logged()
+            .context("Failed to send request to GitHub API")?;
+
+        if !response.status().is_success() {
+            let (status, body) = github::read_error_body(response);
+            return Err(match status {
+                StatusCode::NOT_FOUND => anyhow!("Repository {}/{} not found", owner, repo),
+                StatusCode::UNPROCESSABLE_ENTITY
+                    if body
+                        .as_deref()
+                        .is_some_and(|text| text.contains("name already exists")) =>
+                {
+                    anyhow!(
+                        "A repository named '{}' already exists on {}",
+                        new_name,
+                        owner
+                    )
+                }
+                _ => github::api_error_from(status, body),
+            });
+        }
+
+        let renamed: RenameResponse = response
+            .json()
+            .context("Failed to parse GitHub API response")?;
+
+        outln!(
+            "\n{}",
+            Style::new()
+                .green()
+                .bold()
+                .apply_to("✅ Repository renamed successfully!")
+        );
+        outln!("   Name: {}", renamed.full_name);
+        outln!("   URL: {}", renamed.html_url);
+
+        let remote = if self.config.clone_over_ssh() {
+            &renamed.ssh_url
+        } else {
+            &renamed.clone_url
+        };
+        outln!(
+            "\n⚠️  Existing clones still use the old URL. GitHub redirects it for now, but update them with:"
+        );
+        outln!(
+            "   {}",
+            style(format!("git remote set-url origin {}", remote)).cyan()
+        );
+
+        Ok(())
+    }
+}
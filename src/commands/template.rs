@@ -0,0 +1,146 @@
+use crate::cli;
+use crate::outln;
+use crate::utils::output::Example;
+use crate::utils::preset::{self, Preset};
+use anyhow::{Result, anyhow};
+use console::style;
+
+/// Invocations printed by `repogen template --examples`
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        description: "Save the usual setup for backend services",
+        command: "repogen template save backend --private -d \"{name}: backend service\" --license MIT --gitignore Rust -t rust -t service --label needs-review:fbca04",
+    },
+    Example {
+        description: "Create a repository from it",
+        command: "repogen new payments --template-preset backend",
+    },
+    Example {
+        description: "List the saved presets",
+        command: "repogen template list",
+    },
+    Example {
+        description: "See what a preset sets",
+        command: "repogen template show backend",
+    },
+];
+
+/// Saves, lists and removes presets of `new` flags
+pub struct TemplateHandler {
+    args: cli::Template,
+}
+
+impl TemplateHandler {
+    /// Create a new TemplateHandler instance
+    pub fn new(args: cli::Template) -> Self {
+        Self { args }
+    }
+
+    /// Run the requested template subcommand, listing presets by default
+    pub fn run(&self) -> Result<()> {
+        match &self.args.command {
+            Some(cli::TemplateCommand::Save(save)) => self.save(save),
+            Some(cli::TemplateCommand::Show { name }) => self.show(name),
+            Some(cli::TemplateCommand::Delete { name }) => self.delete(name),
+            Some(cli::TemplateCommand::List) | None => self.list(),
+        }
+    }
+
+    /// Show every saved preset
+    fn list(&self) -> Result<()> {
+        let names = preset::list()?;
+
+        outln!("\n{}", style("🧩 Presets").cyan().bold());
+        outln!("{}", style("━".repeat(50)).dim());
+
+        if names.is_empty() {
+            outln!(
+                "  None yet. Save one with {}",
+                style("repogen template save <name> [flags]").cyan()
+            );
+        }
+        for name in &names {
+            outln!("  {}", name);
+        }
+
+        Ok(())
+    }
+
+    /// Save the given flags under a name
+    fn save(&self, args: &cli::TemplateSave) -> Result<()> {
+        if !args.force && preset::preset_file(&args.name)?.exists() {
+            return Err(anyhow!(
+                "A preset named '{}' already exists. Pass --force to replace it.",
+                args.name
+            ));
+        }
+
+        let preset = Preset::from_args(args)?;
+        let path = preset::save(&args.name, &preset)?;
+
+        outln!("✅ Saved preset '{}' to {}", args.name, path.display());
+        outln!(
+            "💡 Use it with {}",
+            style(format!(
+                "repogen new <name> --template-preset {}",
+                args.name
+            ))
+            .cyan()
+        );
+
+        Ok(())
+    }
+
+    /// Print the flags a preset fills in
+    fn show(&self, name: &str) -> Result<()> {
+        let preset = preset::load(name)?;
+        let unset = || style("not set").dim().to_string();
+        let text = |value: &Option<String>| value.clone().unwrap_or_else(unset);
+
+        outln!("\n{}", style(format!("🧩 Preset '{}'", name)).cyan().bold());
+        outln!("{}", style("━".repeat(50)).dim());
+        outln!("  Description: {}", text(&preset.description));
+        outln!(
+            "  Visibility: {}",
+            match preset.private {
+                Some(true) => "Private 🔒".to_string(),
+                Some(false) => "Public 🌍".to_string(),
+                None => unset(),
+            }
+        );
+        outln!("  License: {}", text(&preset.license));
+        outln!("  .gitignore: {}", text(&preset.gitignore));
+        outln!(
+            "  Topics: {}",
+            if preset.topics.is_empty() {
+                unset()
+            } else {
+                preset.topics.join(", ")
+            }
+        );
+        outln!("  README template: {}", text(&preset.readme_template));
+        outln!("  Default branch: {}", text(&preset.default_branch));
+        outln!(
+            "  Protect branch: {}",
+            if preset.protect { "Yes" } else { "No" }
+        );
+        outln!("  Post-clone command: {}", text(&preset.post_clone));
+        if preset.labels.is_empty() {
+            outln!("  Labels: {}", unset());
+        } else {
+            outln!("  Labels:");
+            for label in &preset.labels {
+                outln!("    {} (#{})", label.name, label.color);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Remove a saved preset
+    fn delete(&self, name: &str) -> Result<()> {
+        preset::delete(name)?;
+        outln!("✅ Deleted preset '{}'", name);
+        Ok(())
+    }
+}
@@ -0,0 +1,206 @@
+use crate::cli;
+use crate::outln;
+use crate::utils::config::Config;
+use crate::utils::github;
+use crate::utils::http::{self, ClientOptions, SendLogged};
+use crate::utils::output::Example;
+use crate::utils::prompt::{self, Assumed};
+use crate::utils::symbols;
+use anyhow::{Context, Result, anyhow};
+use console::Style;
+use dialoguer::theme::ColorfulTheme;
+use indicatif::{ProgressBar, ProgressStyle};
+use reqwest::blocking::Client;
+use serde::Serialize;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Invocations printed by `repogen transfer --examples`
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        description: "Move a repository into an organization",
+        command: "repogen transfer myapp acme",
+    },
+    Example {
+        description: "Move it and give two teams in the organization access",
+        command: "repogen transfer myapp acme --team-id 12345 --team-id 67890",
+    },
+    Example {
+        description: "Hand a repository to another user",
+        command: "repogen transfer myapp octocat",
+    },
+];
+
+/// How long to wait for a transferred repository to appear at its new location
+const TRANSFER_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Delay between checks of the new location
+const TRANSFER_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Handles transferring a repository to a new owner
+pub struct TransferHandler {
+    config: Config,
+    args: cli::Transfer,
+    name: String,
+    new_owner: String,
+    api_version: String,
+    token: Option<String>,
+    assume_yes: bool,
+    client: Client,
+}
+
+/// Request body for a repository transfer
+#[derive(Debug, Serialize)]
+struct TransferRequest<'a> {
+    new_owner: &'a str,
+    #[serde(skip_serializing_if = "<[u64]>::is_empty")]
+    team_ids: &'a [u64],
+}
+
+impl TransferHandler {
+    /// Create a new TransferHandler instance
+    pub fn new(args: cli::Transfer, global: &cli::GlobalArgs) -> Result<Self> {
+        let config = Config::load_or_recover().context("Failed to load configuration")?;
+        config.print_warnings();
+        let api_version = global
+            .api_version
+            .clone()
+            .unwrap_or_else(|| config.api_version().to_string());
+        let token = config.resolve_token(global.token_file.as_deref())?;
+        let client = http::client(&ClientOptions::resolve(global, &config))?;
+
+        let name = args.name.clone().context("No repository given")?;
+        let new_owner = args.new_owner.clone().context("No new owner given")?;
+
+        Ok(Self {
+            config,
+            args,
+            name,
+            new_owner,
+            api_version,
+            token,
+            assume_yes: global.assume_yes,
+            client,
+        })
+    }
+
+    /// Transfer the repository and wait for it to arrive
+    pub fn run(&self) -> Result<()> {
+        let cyan = Style::new().cyan().bold();
+        let green = Style::new().green().bold();
+
+        let token = self.token.as_ref().ok_or_else(|| {
+            anyhow!("No GitHub token found. Run `repogen init --auth` to authenticate.")
+        })?;
+        let (owner, repo) = github::split_repo(&self.name, self.config.github_username.as_deref())?;
+
+        if owner.eq_ignore_ascii_case(&self.new_owner) {
+            return Err(anyhow!(
+                "{}/{} is already owned by {}",
+                owner,
+                repo,
+                self.new_owner
+            ));
+        }
+
+        outln!(
+            "\n{}",
+            cyan.apply_to(format!(
+                "🚚 Transfer {}/{} to {}",
+                owner, repo, self.new_owner
+            ))
+        );
+
+        let confirmed = prompt::confirm(
+            &ColorfulTheme::default(),
+            self.assume_yes,
+            &format!(
+                "Transfer {}/{} to {}? You may lose access to it",
+                owner, repo, self.new_owner
+            ),
+            false,
+            Assumed::Yes,
+        );
+        if !confirmed {
+            outln!("❌ Transfer cancelled.");
+            return Ok(());
+        }
+
+        let client = &self.client;
+        let url = format!("{}/repos/{}/{}/transfer", github::api_base(), owner, repo);
+        let request = TransferRequest {
+            new_owner: &self.new_owner,
+            team_ids: &self.args.team_ids,
+        };
+
+        let response = github::with_headers(client.post(&url), token, &self.api_version)
+            .json(&request)
+            .send_logged()
+            .context("Failed to send request to GitHub API")?;
+
+        if !response.status().is_success() {
+            return Err(github::api_error(response));
+        }
+
+        let Some(html_url) = self.wait_for_new_location(client, token, &repo)? else {
+            outln!(
+                "\n⏳ Transfer still pending. Transfers to a user must be accepted by them first; check {}/{}/{} later.",
+                github::web_base(),
+                self.new_owner,
+                repo
+            );
+            return Ok(());
+        };
+
+        outln!(
+            "\n{}",
+            green.apply_to("✅ Repository transferred successfully!")
+        );
+        outln!("   URL: {}", html_url);
+
+        Ok(())
+    }
+
+    /// Poll the new location until GitHub serves the repository there
+    ///
+    /// Transfers run in the background, so the repository can briefly 404
+    /// at its new home. Transfers to a user who must accept them never
+    /// arrive on their own, so a timeout isn't treated as a failure and
+    /// returns `None`.
+    fn wait_for_new_location(
+        &self,
+        client: &Client,
+        token: &str,
+        repo: &str,
+    ) -> Result<Option<String>> {
+        let pb = ProgressBar::new_spinner();
+        pb.set_style(
+            ProgressStyle::default_spinner()
+                .tick_chars(symbols::spinner_ticks())
+                .template("{spinner:.cyan} {msg}")
+                .unwrap(),
+        );
+        pb.set_message(format!("Waiting for {}/{}...", self.new_owner, repo));
+        pb.enable_steady_tick(Duration::from_millis(120));
+
+        let started = Instant::now();
+
+        loop {
+            if let Some(moved) =
+                github::find_repo(client, token, &self.api_version, &self.new_owner, repo)?
+            {
+                pb.finish_with_message(
+                    symbols::apply("✅ Repository is available at its new location").into_owned(),
+                );
+                return Ok(Some(moved.html_url));
+            }
+
+            if started.elapsed() >= TRANSFER_TIMEOUT {
+                pb.finish_and_clear();
+                return Ok(None);
+            }
+
+            thread::sleep(TRANSFER_POLL_INTERVAL);
+        }
+    }
+}
@@ -0,0 +1,207 @@
+use crate::cli;
+use crate::outln;
+use crate::utils::config::{self, Config};
+use crate::utils::github;
+use crate::utils::http::{self, ClientOptions, SendLogged};
+use crate::utils::output::Example;
+use anyhow::{Context, Result, anyhow};
+use console::Style;
+use reqwest::blocking::Client;
+use serde::{Deserialize, Serialize};
+
+/// Invocations printed by `repogen update --examples`
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        description: "Set the homepage",
+        command: "repogen update myapp --homepage https://myapp.dev",
+    },
+    Example {
+        description: "Change the description and make the repository private",
+        command: "repogen edit myapp --desc \"Payments API\" --private",
+    },
+    Example {
+        description: "Replace all topics",
+        command: "repogen update myapp -t rust -t cli",
+    },
+    Example {
+        description: "Update a repository in an organization",
+        command: "repogen update acme/myapp --homepage https://acme.dev/myapp",
+    },
+    Example {
+        description: "Remove every topic and clear the homepage",
+        command: "repogen update myapp --clear-topics --homepage \"\"",
+    },
+];
+
+/// Handles updating metadata of an existing repository
+pub struct UpdateHandler {
+    config: Config,
+    args: cli::Update,
+    name: String,
+    api_version: String,
+    token: Option<String>,
+    client: Client,
+}
+
+/// Request body for repository updates; only the given fields are sent
+#[derive(Debug, Serialize)]
+struct UpdateRepoRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    homepage: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    private: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_branch: Option<String>,
+}
+
+impl UpdateRepoRequest {
+    /// Whether the request would change anything
+    fn is_empty(&self) -> bool {
+        self.description.is_none()
+            && self.homepage.is_none()
+            && self.private.is_none()
+            && self.default_branch.is_none()
+    }
+}
+
+/// Response from repository update endpoints
+#[derive(Debug, Deserialize)]
+struct UpdateRepoResponse {
+    html_url: String,
+    description: Option<String>,
+    homepage: Option<String>,
+    private: bool,
+    default_branch: String,
+}
+
+impl UpdateHandler {
+    /// Create a new UpdateHandler instance
+    pub fn new(args: cli::Update, global: &cli::GlobalArgs) -> Result<Self> {
+        let config = Config::load_or_recover().context("Failed to load configuration")?;
+        config.print_warnings();
+        let api_version = global
+            .api_version
+            .clone()
+            .unwrap_or_else(|| config.api_version().to_string());
+        let token = config.resolve_token(global.token_file.as_deref())?;
+        let client = http::client(&ClientOptions::resolve(global, &config))?;
+
+        let name = args.name.clone().context("No repository given")?;
+
+        Ok(Self {
+            config,
+            args,
+            name,
+            api_version,
+            token,
+            client,
+        })
+    }
+
+    /// Apply the requested changes to the repository
+    pub fn run(&self) -> Result<()> {
+        let cyan = Style::new().cyan().bold();
+        let green = Style::new().green().bold();
+
+        let request = UpdateRepoRequest {
+            description: self.args.description.clone(),
+            homepage: self.args.homepage.clone(),
+            private: config::requested_privacy(Some(self.args.private), Some(self.args.public)),
+            default_branch: self.args.default_branch.clone(),
+        };
+
+        if request.is_empty() && self.args.topic.is_empty() && !self.args.clear_topics {
+            return Err(anyhow!(
+                "Nothing to update. Pass --desc, --homepage, --private, --public, --default-branch, --topic or --clear-topics."
+            ));
+        }
+
+        // Validate everything before making any changes
+        if let Some(homepage) = &self.args.homepage
+            && !homepage.is_empty()
+        {
+            github::validate_homepage(homepage)?;
+        }
+        github::validate_topics(&self.args.topic)?;
+
+        let token = self.token.as_ref().ok_or_else(|| {
+            anyhow!("No GitHub token found. Run `repogen init --auth` to authenticate.")
+        })?;
+        let (owner, repo) = github::split_repo(&self.name, self.config.github_username.as_deref())?;
+
+        outln!(
+            "\n{}",
+            cyan.apply_to(format!("✏️  Updating {}/{}...", owner, repo))
+        );
+
+        let client = &self.client;
+        let repo_url = format!("{}/repos/{}/{}", github::api_base(), owner, repo);
+
+        if !request.is_empty() {
+            let response = github::with_headers(client.patch(&repo_url), token, &self.api_version)
+                .json(&request)
+                .send_logged()
+                .context("Failed to send request to GitHub API")?;
+
+            if !response.status().is_success() {
+                return Err(github::api_error(response));
+            }
+
+            let updated: UpdateRepoResponse = response
+                .json()
+                .context("Failed to parse GitHub API response")?;
+
+            if request.description.is_some() {
+                match updated.description.filter(|d| !d.is_empty()) {
+                    Some(description) => outln!("   Description: {}", description),
+                    None => outln!("   Description: cleared"),
+                }
+            }
+            if request.homepage.is_some() {
+                match updated.homepage.filter(|h| !h.is_empty()) {
+                    Some(homepage) => outln!("   Homepage: {}", homepage),
+                    None => outln!("   Homepage: cleared"),
+                }
+            }
+            if request.private.is_some() {
+                outln!(
+                    "   Visibility: {}",
+                    if updated.private {
+                        "Private 🔒"
+                    } else {
+                        "Public 🌍"
+                    }
+                );
+            }
+            if request.default_branch.is_some() {
+                outln!("   Default branch: {}", updated.default_branch);
+            }
+            outln!("   URL: {}", updated.html_url);
+        }
+
+        if self.args.clear_topics || !self.args.topic.is_empty() {
+            let topics = github::replace_topics(
+                client,
+                token,
+                &self.api_version,
+                &format!("{}/{}", owner, repo),
+                &self.args.topic,
+            )?;
+
+            if topics.is_empty() {
+                outln!("   Topics: cleared");
+            } else {
+                outln!("   Topics: {}", topics.join(", "));
+            }
+        }
+
+        outln!(
+            "\n{}",
+            green.apply_to("✅ Repository updated successfully!")
+        );
+
+        Ok(())
+    }
+}
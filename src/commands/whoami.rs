@@ -0,0 +1,144 @@
+use crate::cli;
+use crate::outln;
+use crate::utils::config::Config;
+use crate::utils::github::{self, TokenInfo};
+use crate::utils::http::{self, ClientOptions};
+use crate::utils::output::{self, Example, OutputFormat};
+use anyhow::{Context, Result, anyhow};
+use console::style;
+use reqwest::blocking::Client;
+
+/// Invocations printed by `repogen whoami --examples`
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        description: "See which GitHub account this machine uses",
+        command: "repogen whoami",
+    },
+    Example {
+        description: "Check the account of another profile",
+        command: "repogen --profile work whoami",
+    },
+    Example {
+        description: "Just the login, for scripts",
+        command: "repogen whoami --porcelain | cut -f1",
+    },
+];
+
+/// Shows the GitHub account the configured token belongs to
+pub struct WhoamiHandler {
+    args: cli::Whoami,
+    config: Config,
+    api_version: String,
+    token: Option<String>,
+    client: Client,
+}
+
+impl WhoamiHandler {
+    /// Create a new WhoamiHandler instance
+    pub fn new(args: cli::Whoami, global: &cli::GlobalArgs) -> Result<Self> {
+        let config = Config::load_or_recover().context("Failed to load configuration")?;
+        config.print_warnings();
+        let api_version = global
+            .api_version
+            .clone()
+            .unwrap_or_else(|| config.api_version().to_string());
+        let token = config.resolve_token(global.token_file.as_deref())?;
+        let client = http::client(&ClientOptions::resolve(global, &config))?;
+
+        Ok(Self {
+            args,
+            config,
+            api_version,
+            token,
+            client,
+        })
+    }
+
+    /// Look up the token's user and print it
+    pub fn run(&self) -> Result<()> {
+        let token = self.token.as_ref().ok_or_else(|| {
+            anyhow!("No GitHub token found. Run `repogen init --auth` to authenticate.")
+        })?;
+
+        let info = github::validate_github_token(&self.client, token, &self.api_version)?;
+        let config_file = Config::config_file()?.display().to_string();
+
+        match OutputFormat::from_porcelain(self.args.porcelain) {
+            OutputFormat::Human => self.print_details(&info, &config_file),
+            OutputFormat::Porcelain => self.print_porcelain(&info, &config_file),
+        }
+
+        Ok(())
+    }
+
+    /// Print the account as labelled lines
+    fn print_details(&self, info: &TokenInfo, config_file: &str) {
+        let none = || style("not set").dim().to_string();
+
+        outln!(
+            "\n{}",
+            style(format!("👤 Signed in to {}", self.config.github_host()))
+                .cyan()
+                .bold()
+        );
+        outln!("{}", style("━".repeat(50)).dim());
+        outln!("  Login: {}", style(&info.user.login).green());
+        outln!("  Name: {}", info.user.name.clone().unwrap_or_else(none));
+        outln!("  Account: {}", Self::account(info));
+        outln!("  Scopes: {}", Self::scopes(info));
+        outln!("  Config: {}", style(config_file).dim());
+
+        let missing = info.missing_scopes();
+        if !missing.is_empty() {
+            outln!(
+                "\n⚠️  Missing the {} scope needed to create repositories. Run {} with a new token.",
+                missing.join(", "),
+                style("repogen init --auth").cyan()
+            );
+        }
+    }
+
+    /// Print one stable tab-separated record
+    ///
+    /// Fields: login, name, account type, plan, comma-separated scopes, host,
+    /// config file. Unknown values are printed as `-`.
+    fn print_porcelain(&self, info: &TokenInfo, config_file: &str) {
+        let scopes = info
+            .scopes
+            .as_ref()
+            .map(|scopes| scopes.join(","))
+            .unwrap_or_default();
+
+        output::print_porcelain(&[
+            &info.user.login,
+            info.user.name.as_deref().unwrap_or(""),
+            info.user.account_type.as_deref().unwrap_or(""),
+            info.user
+                .plan
+                .as_ref()
+                .map(|plan| plan.name.as_str())
+                .unwrap_or(""),
+            &scopes,
+            self.config.github_host(),
+            config_file,
+        ]);
+    }
+
+    /// Account type with the plan when GitHub shares it, e.g. `User (pro plan)`
+    fn account(info: &TokenInfo) -> String {
+        let account_type = info.user.account_type.as_deref().unwrap_or("User");
+        match &info.user.plan {
+            Some(plan) => format!("{} ({} plan)", account_type, plan.name),
+            None => account_type.to_string(),
+        }
+    }
+
+    /// The token's scopes, or why they aren't known
+    fn scopes(info: &TokenInfo) -> String {
+        match &info.scopes {
+            Some(scopes) if !scopes.is_empty() => scopes.join(", "),
+            Some(_) => style("none").dim().to_string(),
+            None => style("not reported for this token type").dim().to_string(),
+        }
+    }
+}
@@ -4,60 +4,303 @@ mod cli;
 mod commands;
 mod utils;
 
-use commands::{InitHandler, NewHandler};
+use commands::{
+    ArchiveHandler, BootstrapHandler, CloneHandler, DoctorHandler, InitHandler, LastHandler,
+    ListHandler, NewHandler, ProfileHandler, RenameHandler, TemplateHandler, TransferHandler,
+    UpdateHandler, WhoamiHandler,
+};
 
 fn main() {
     let args = cli::Cli::parse();
+    init_logging(args.global.verbose);
+
+    // Every config read below goes to the selected profile's file
+    if let Err(e) = utils::config::select_profile(args.global.profile.as_deref()) {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    }
+
+    // Pick output symbols before anything is printed
+    let config = utils::config::Config::read_file().ok();
+    let symbol_set = config.as_ref().and_then(|config| config.symbol_set.clone());
+    utils::symbols::init(symbol_set.as_deref());
+
+    // Point API requests at GitHub Enterprise Server when configured
+    let api_url = args
+        .global
+        .api_url
+        .clone()
+        .or_else(|| config.and_then(|config| config.api_base_url));
+    utils::github::set_api_base(api_url.as_deref());
+
+    // Print usage examples instead of running the command
+    if let Some((name, examples)) = commands::examples(&args.command) {
+        utils::output::print_examples(name, examples);
+        return;
+    }
 
     match args.command {
         cli::Commands::Init(init) => {
             // Display the cool title
             utils::display_title();
 
-            if init.authentication {
+            if let Some(path) = &init.import {
+                // Merge an existing config file into this profile
+                InitHandler::handle_import(&args.global, path, init.force);
+            } else if init.authentication {
                 // Authentication-only setup
-                InitHandler::handle_auth_only();
+                InitHandler::handle_auth_only(&args.global, init.dry_run);
+            } else if init.oauth_setup {
+                // OAuth app setup only, without authenticating
+                InitHandler::handle_oauth_setup_only(&args.global);
             } else if init.metadata {
                 // Metadata-only setup (profile and preferences)
-                InitHandler::handle_meta_only();
+                InitHandler::handle_meta_only(&args.global, init.force);
             } else {
                 // Full initialization workflow
-                InitHandler::handle_init();
+                InitHandler::handle_init(&args.global, init.force);
             }
         }
         cli::Commands::New(new) => {
             // Create new repository on GitHub
-            match NewHandler::new(new) {
+            let scripted = new.quiet || new.json;
+            match NewHandler::new(new, &args.global) {
                 Ok(mut handler) => {
-                    if let Err(e) = handler.create_repository() {
-                        eprintln!("\n❌ Error creating repository: {}", e);
-                        eprintln!("\n💡 Make sure you have:");
-                        eprintln!("   1. Authenticated with GitHub (run: repogen init --auth)");
-                        eprintln!("   2. A valid GitHub token with 'repo' scope");
-                        eprintln!("   3. Internet connection");
+                    if let Err(e) = handler
+                        .create_repository()
+                        .map_err(utils::http::explain_timeout)
+                    {
+                        if scripted {
+                            exit_with_plain_error(&e);
+                        }
+                        errln!("\n❌ Error creating repository: {}", e);
+                        errln!("\n💡 Run `repogen doctor` to check your setup");
                         std::process::exit(1);
                     }
                 }
+                Err(e) if scripted => exit_with_plain_error(&e),
                 Err(e) => {
-                    eprintln!("\n❌ Failed to initialize: {}", e);
-                    eprintln!("\n💡 Try running: repogen init");
+                    errln!("\n❌ Failed to initialize: {}", e);
+                    errln!("\n💡 Try running: repogen init");
                     std::process::exit(1);
                 }
             }
         }
+        cli::Commands::Config(config) if config.env => {
+            // Only shell statements on stdout, so they can be eval'd
+            commands::handle_config_env(&args.global, config.include_token);
+        }
         cli::Commands::Config(config) => {
             utils::display_title();
 
-            if config.view {
-                commands::handle_config_view();
+            if config.view || config.merge_env {
+                commands::handle_config_view(&args.global, config.merge_env);
+            } else if let Some(path) = &config.export {
+                commands::handle_config_export(&args.global, path, config.include_secrets);
+            } else if !config.set.is_empty() {
+                commands::handle_config_set(&args.global, &config.set, config.force);
+            } else if config.lock || config.unlock {
+                commands::handle_config_lock(&args.global, config.lock);
             } else if config.edit {
-                commands::handle_config_edit();
+                commands::handle_config_edit(&args.global, config.force);
+            } else if config.clear && config.interactive {
+                commands::handle_config_clear_sections(&args.global, config.force);
             } else if config.clear {
-                commands::handle_config_clear();
+                commands::handle_config_clear(&args.global, config.force);
+            } else if config.doctor_fix {
+                commands::handle_config_doctor_fix(&args.global, config.force);
             } else {
                 // Default to view if no flag is provided
-                commands::handle_config_view();
+                commands::handle_config_view(&args.global, config.merge_env);
+            }
+        }
+        cli::Commands::Clone(clone) => {
+            // Clone existing repositories from GitHub
+            match CloneHandler::new(clone, &args.global) {
+                Ok(handler) => {
+                    if let Err(e) = handler.run().map_err(utils::http::explain_timeout) {
+                        errln!("\n❌ Error cloning: {}", e);
+                        std::process::exit(1);
+                    }
+                }
+                Err(e) => {
+                    errln!("\n❌ Failed to initialize: {}", e);
+                    errln!("\n💡 Try running: repogen init");
+                    std::process::exit(1);
+                }
+            }
+        }
+        cli::Commands::Update(update) => {
+            // Update metadata of an existing repository
+            match UpdateHandler::new(update, &args.global) {
+                Ok(handler) => {
+                    if let Err(e) = handler.run().map_err(utils::http::explain_timeout) {
+                        errln!("\n❌ Error updating repository: {}", e);
+                        std::process::exit(1);
+                    }
+                }
+                Err(e) => {
+                    errln!("\n❌ Failed to initialize: {}", e);
+                    errln!("\n💡 Try running: repogen init");
+                    std::process::exit(1);
+                }
+            }
+        }
+        cli::Commands::Transfer(transfer) => {
+            // Move a repository to a new owner
+            match TransferHandler::new(transfer, &args.global) {
+                Ok(handler) => {
+                    if let Err(e) = handler.run().map_err(utils::http::explain_timeout) {
+                        errln!("\n❌ Error transferring repository: {}", e);
+                        std::process::exit(1);
+                    }
+                }
+                Err(e) => {
+                    errln!("\n❌ Failed to initialize: {}", e);
+                    errln!("\n💡 Try running: repogen init");
+                    std::process::exit(1);
+                }
+            }
+        }
+        cli::Commands::Rename(rename) => {
+            // Give a repository a new name
+            match RenameHandler::new(rename, &args.global) {
+                Ok(handler) => {
+                    if let Err(e) = handler.run().map_err(utils::http::explain_timeout) {
+                        errln!("\n❌ Error renaming repository: {}", e);
+                        std::process::exit(1);
+                    }
+                }
+                Err(e) => {
+                    errln!("\n❌ Failed to initialize: {}", e);
+                    errln!("\n💡 Try running: repogen init");
+                    std::process::exit(1);
+                }
+            }
+        }
+        cli::Commands::Archive(archive) => {
+            // Archive or unarchive a repository
+            match ArchiveHandler::new(archive, &args.global) {
+                Ok(handler) => {
+                    if let Err(e) = handler.run().map_err(utils::http::explain_timeout) {
+                        errln!("\n❌ Error archiving repository: {}", e);
+                        std::process::exit(1);
+                    }
+                }
+                Err(e) => {
+                    errln!("\n❌ Failed to initialize: {}", e);
+                    errln!("\n💡 Try running: repogen init");
+                    std::process::exit(1);
+                }
+            }
+        }
+        cli::Commands::List(list) => {
+            // List existing repositories
+            match ListHandler::new(list, &args.global) {
+                Ok(handler) => {
+                    if let Err(e) = handler.run().map_err(utils::http::explain_timeout) {
+                        errln!("\n❌ Error listing repositories: {}", e);
+                        std::process::exit(1);
+                    }
+                }
+                Err(e) => {
+                    errln!("\n❌ Failed to initialize: {}", e);
+                    errln!("\n💡 Try running: repogen init");
+                    std::process::exit(1);
+                }
+            }
+        }
+        cli::Commands::Bootstrap(bootstrap) => {
+            // Walk through creating a new project
+            utils::display_title();
+
+            match BootstrapHandler::new(bootstrap, &args.global) {
+                Ok(handler) => {
+                    if let Err(e) = handler.run().map_err(utils::http::explain_timeout) {
+                        errln!("\n❌ Error bootstrapping project: {}", e);
+                        std::process::exit(1);
+                    }
+                }
+                Err(e) => {
+                    errln!("\n❌ Failed to initialize: {}", e);
+                    errln!("\n💡 Try running: repogen init");
+                    std::process::exit(1);
+                }
+            }
+        }
+        cli::Commands::Profile(profile) => {
+            // List or switch config profiles
+            if let Err(e) = ProfileHandler::new(profile).run() {
+                errln!("\n❌ Error managing profiles: {}", e);
+                std::process::exit(1);
+            }
+        }
+        cli::Commands::Template(template) => {
+            // Save, list or remove presets for `new`
+            if let Err(e) = TemplateHandler::new(template).run() {
+                errln!("\n❌ Error managing presets: {}", e);
+                std::process::exit(1);
+            }
+        }
+        cli::Commands::Whoami(whoami) => {
+            // Show the account behind the configured token
+            let porcelain = whoami.porcelain;
+            match WhoamiHandler::new(whoami, &args.global) {
+                Ok(handler) => {
+                    if let Err(e) = handler.run().map_err(utils::http::explain_timeout) {
+                        if porcelain {
+                            exit_with_plain_error(&e);
+                        }
+                        errln!("\n❌ Error looking up your account: {}", e);
+                        std::process::exit(1);
+                    }
+                }
+                Err(e) if porcelain => exit_with_plain_error(&e),
+                Err(e) => {
+                    errln!("\n❌ Failed to initialize: {}", e);
+                    errln!("\n💡 Try running: repogen init");
+                    std::process::exit(1);
+                }
+            }
+        }
+        cli::Commands::Last(last) => {
+            // Show the last repository `new` created
+            let porcelain = last.porcelain;
+            if let Err(e) = LastHandler::new(last).run() {
+                if porcelain {
+                    exit_with_plain_error(&e);
+                }
+                errln!("\n❌ {}", e);
+                std::process::exit(1);
+            }
+        }
+        cli::Commands::Doctor(doctor) => {
+            // Check that repogen is ready to use
+            if let Err(e) = DoctorHandler::new(doctor, &args.global).run() {
+                errln!("\n❌ {}", e);
+                std::process::exit(1);
             }
         }
     }
 }
+
+/// Send repogen's log to stderr at the level picked by `--verbose`
+fn init_logging(verbose: u8) {
+    let level = match verbose {
+        0 => return,
+        1 => log::LevelFilter::Info,
+        2 => log::LevelFilter::Debug,
+        _ => log::LevelFilter::Trace,
+    };
+
+    env_logger::Builder::new()
+        .filter_module("repogen", level)
+        .format_timestamp_millis()
+        .init();
+}
+
+/// Report an error on one undecorated line and exit, for scripts and CI logs
+fn exit_with_plain_error(e: &anyhow::Error) -> ! {
+    eprintln!("Error: {}", e);
+    std::process::exit(1);
+}
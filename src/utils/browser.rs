@@ -0,0 +1,32 @@
+use crate::outln;
+use console::style;
+use std::env;
+
+/// Whether there's likely no graphical session to show a browser in
+///
+/// `webbrowser::open` can report success on servers and in containers (for
+/// example through a stub `xdg-open`) without anything appearing on screen.
+pub fn is_headless() -> bool {
+    let ssh_session = env::var_os("SSH_CONNECTION").is_some() || env::var_os("SSH_TTY").is_some();
+    let no_display = cfg!(all(unix, not(target_os = "macos")))
+        && env::var_os("DISPLAY").is_none()
+        && env::var_os("WAYLAND_DISPLAY").is_none();
+
+    ssh_session || no_display
+}
+
+/// Open `url` in a browser, or print it prominently where that can't work
+pub fn open(url: &str) {
+    if is_headless() {
+        outln!("🔗 No browser available here. Open this URL on any device:");
+        outln!("   {}", style(url).cyan().bold().underlined());
+        return;
+    }
+
+    if webbrowser::open(url).is_ok() {
+        outln!("✅ Browser opened automatically");
+    } else {
+        outln!("⚠️  Could not open browser automatically. Open this URL:");
+        outln!("   {}", style(url).cyan().bold().underlined());
+    }
+}
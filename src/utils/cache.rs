@@ -0,0 +1,70 @@
+use crate::utils::config::Config;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// How long a fetched list is used before asking GitHub again
+const MAX_AGE: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// Names fetched from GitHub, with when they were fetched
+#[derive(Serialize, Deserialize)]
+struct CachedList {
+    /// Seconds since the Unix epoch
+    fetched_at: u64,
+    items: Vec<String>,
+}
+
+/// A list GitHub rarely changes, cached for a week in `file` under the config directory
+///
+/// An outdated cache is still used when `fetch` fails.
+pub fn weekly(file: &str, fetch: impl FnOnce() -> Result<Vec<String>>) -> Result<Vec<String>> {
+    let cached = read_list(file);
+    if let Some(cache) = &cached
+        && now().saturating_sub(cache.fetched_at) < MAX_AGE.as_secs()
+    {
+        return Ok(cache.items.clone());
+    }
+
+    match fetch() {
+        Ok(items) => {
+            // The cache only saves requests, so failing to write it is fine
+            let _ = write_list(file, &items);
+            Ok(items)
+        }
+        Err(e) => cached.map(|cache| cache.items).ok_or(e),
+    }
+}
+
+/// The cached list in `file`, however old, without going online
+pub fn read(file: &str) -> Option<Vec<String>> {
+    read_list(file).map(|cache| cache.items)
+}
+
+fn path(file: &str) -> Result<PathBuf> {
+    Ok(Config::config_dir()?.join(file))
+}
+
+fn read_list(file: &str) -> Option<CachedList> {
+    let content = fs::read_to_string(path(file).ok()?).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+fn write_list(file: &str, items: &[String]) -> Result<()> {
+    let cache = CachedList {
+        fetched_at: now(),
+        items: items.to_vec(),
+    };
+    fs::create_dir_all(Config::config_dir()?)?;
+    fs::write(path(file)?, serde_json::to_string(&cache)?)?;
+    Ok(())
+}
+
+/// Seconds since the Unix epoch
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or(0)
+}
@@ -1,23 +1,353 @@
-use anyhow::{Context, Result};
+use crate::cli;
+use crate::utils::github::{self, RetryPolicy};
+use crate::utils::gitignore;
+use crate::utils::secrets;
+use crate::utils::suggest;
+use crate::{errln, outln};
+use anyhow::{Context, Result, anyhow};
+use dialoguer::{Select, theme::ColorfulTheme};
 use dirs::home_dir;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::env;
+use std::ffi::OsString;
 use std::fs;
-use std::path::PathBuf;
+use std::io::Write;
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command};
+use std::sync::OnceLock;
+use std::time::Duration;
 
-#[derive(Serialize, Deserialize, Default)]
+/// License templates offered in prompts ("None" first)
+pub const LICENSE_OPTIONS: &[&str] = &[
+    "None",
+    "MIT",
+    "Apache-2.0",
+    "GPL-3.0",
+    "BSD-3-Clause",
+    "Unlicense",
+];
+
+/// .gitignore templates offered in prompts ("None" first)
+pub const GITIGNORE_OPTIONS: &[&str] = &[
+    "None", "Node", "Python", "Rust", "Go", "Java", "C++", "Swift",
+];
+
+/// Built-in .gitattributes templates offered in prompts ("None" first)
+pub const GITATTRIBUTES_OPTIONS: &[&str] = &["None", "lf", "lfs-images"];
+
+/// Editors repogen knows how to suggest ("None" first)
+pub const EDITOR_OPTIONS: &[&str] = &[
+    "None",
+    "VS Code",
+    "Vim",
+    "Emacs",
+    "Sublime Text",
+    "Atom",
+    "IntelliJ",
+];
+
+/// Symbol sets for decorative output ("None" first, meaning auto-detect)
+pub const SYMBOL_SET_OPTIONS: &[&str] = &["None", "emoji", "ascii", "nerdfont"];
+
+/// Accepted `clone_protocol` values
+pub const CLONE_PROTOCOL_OPTIONS: &[&str] = &["https", "ssh"];
+
+/// Commit message used for post-creation content commits unless overridden
+pub const DEFAULT_COMMIT_MESSAGE: &str = "Initial scaffold via repogen";
+
+/// Environment variable naming a file that contains the GitHub token
+pub const TOKEN_FILE_ENV: &str = "REPOGEN_TOKEN_FILE";
+
+/// Environment variables holding a token directly, in order of precedence
+pub const TOKEN_ENVS: &[&str] = &["REPOGEN_TOKEN", "GITHUB_TOKEN"];
+
+/// Most usernames remembered for prompt history
+const MAX_KNOWN_USERNAMES: usize = 10;
+
+/// Placeholder token saved by `repogen init --auth --dry-run`
+pub const DRY_RUN_TOKEN: &str = "repogen-dry-run-placeholder";
+
+/// Host whose token is used when `github_host` isn't set
+pub const DEFAULT_GITHUB_HOST: &str = "github.com";
+
+/// OAuth app client ID baked in at build time from `REPOGEN_OAUTH_CLIENT_ID`, if any
+pub const BUNDLED_OAUTH_CLIENT_ID: Option<&str> = option_env!("REPOGEN_OAUTH_CLIENT_ID");
+
+/// Seconds before an HTTP request is abandoned when `request_timeout_secs` isn't set
+pub const DEFAULT_REQUEST_TIMEOUT_SECS: u64 = 30;
+
+/// Approving reviews `--protect` requires when `required_reviews` isn't set
+pub const DEFAULT_REQUIRED_REVIEWS: u8 = 1;
+
+/// Most approving reviews GitHub lets branch protection require
+pub const MAX_REQUIRED_REVIEWS: u8 = 6;
+
+/// Secondary rate limit retries used when `max_retries` isn't set
+pub const DEFAULT_MAX_RETRIES: u32 = 3;
+
+/// Most secondary rate limit retries accepted from flags or config
+pub const MAX_RETRIES_LIMIT: u32 = 10;
+
+/// First retry pause in milliseconds when `retry_delay_ms` isn't set
+pub const DEFAULT_RETRY_DELAY_MS: u64 = 60_000;
+
+/// Accepted range for `retry_delay_ms`, from 100ms to ten minutes
+pub const RETRY_DELAY_MS_RANGE: RangeInclusive<u64> = 100..=600_000;
+
+/// Value sent in the `X-GitHub-Api-Version` header unless overridden
+pub const DEFAULT_GITHUB_API_VERSION: &str = "2022-11-28";
+
+/// Profile used when none has been selected
+pub const DEFAULT_PROFILE: &str = "default";
+
+/// Fields `repogen config --set` can change
+pub const SETTABLE_KEYS: &[&str] = &[
+    "github_host",
+    "api_base_url",
+    "github_username",
+    "user_name",
+    "user_email",
+    "default_private",
+    "default_license",
+    "default_gitignore",
+    "default_gitattributes",
+    "preferred_editor",
+    "oauth_client_id",
+    "auto_open",
+    "auto_clone",
+    "clone_directory",
+    "clone_protocol",
+    "clone_recurse_submodules",
+    "post_clone_command",
+    "skip_git_config",
+    "set_local_git_identity",
+    "commit_sign",
+    "signing_key",
+    "github_api_version",
+    "sanitize_description",
+    "default_commit_message",
+    "description_template",
+    "default_branch",
+    "enable_secret_scanning",
+    "enable_push_protection",
+    "required_reviews",
+    "require_code_owner_reviews",
+    "dismiss_stale_reviews",
+    "disable_issues",
+    "disable_wiki",
+    "disable_projects",
+    "creation_webhook_url",
+    "symbol_set",
+    "ca_bundle",
+    "max_retries",
+    "request_timeout_secs",
+    "retry_delay_ms",
+];
+
+static PROFILE: OnceLock<String> = OnceLock::new();
+
+/// Choose the profile for this run: `--profile`, else the one saved by
+/// `repogen profile switch`, else `default`
+///
+/// Also moves a config file from before profiles existed into the default
+/// profile. Only the first call has an effect.
+pub fn select_profile(requested: Option<&str>) -> Result<()> {
+    Config::migrate_legacy_file()?;
+
+    let profile = match requested {
+        Some(name) => {
+            validate_profile_name(name)?;
+            name.to_string()
+        }
+        None => Config::saved_profile().unwrap_or_else(|| DEFAULT_PROFILE.to_string()),
+    };
+    let _ = PROFILE.set(profile);
+
+    Ok(())
+}
+
+/// The profile in use
+pub fn active_profile() -> &'static str {
+    PROFILE.get_or_init(|| Config::saved_profile().unwrap_or_else(|| DEFAULT_PROFILE.to_string()))
+}
+
+/// Check a profile name is safe to use as a file name
+pub fn validate_profile_name(name: &str) -> Result<()> {
+    if name.is_empty()
+        || !name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err(anyhow!(
+            "Invalid profile name '{}': use letters, digits, '-' and '_'",
+            name
+        ));
+    }
+
+    Ok(())
+}
+
+/// An issue label created in every new repository
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct Label {
+    pub name: String,
+    /// Hex color without the leading `#`, e.g. `d73a4a`
+    pub color: String,
+    pub description: Option<String>,
+}
+
+/// Whether `color` is six hex digits, optionally after a `#`, as GitHub requires for labels
+pub fn is_label_color(color: &str) -> bool {
+    let color = color.trim_start_matches('#');
+    color.len() == 6 && color.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+#[derive(Clone, Serialize, Deserialize, Default)]
 pub struct Config {
+    /// Single-token field from older configs, moved into `github_tokens` on load
+    #[serde(skip_serializing)]
     pub github_token: Option<String>,
+    pub github_host: Option<String>,
+    /// REST API base URL, for GitHub Enterprise Server (default: https://api.github.com)
+    pub api_base_url: Option<String>,
     pub github_username: Option<String>,
     pub user_name: Option<String>,
     pub user_email: Option<String>,
     pub default_private: bool,
     pub default_license: Option<String>,
     pub default_gitignore: Option<String>,
+    /// Built-in .gitattributes template committed to new repositories (`lf` or `lfs-images`)
+    pub default_gitattributes: Option<String>,
     pub preferred_editor: Option<String>,
     pub oauth_client_id: Option<String>,
+    /// Open new repositories in the browser once they're created
+    #[serde(default)]
+    pub auto_open: bool,
     #[serde(default)]
     pub auto_clone: bool,
     pub clone_directory: Option<String>,
+    /// `https` (the default) or `ssh`, for cloning and the clone hints
+    pub clone_protocol: Option<String>,
+    #[serde(default)]
+    pub clone_recurse_submodules: bool,
+    /// Shell command run inside each repository `repogen new` clones, e.g. `npm install`
+    pub post_clone_command: Option<String>,
+    #[serde(default)]
+    pub skip_git_config: bool,
+    /// Write `user_name` and `user_email` into each clone's own git config
+    #[serde(default)]
+    pub set_local_git_identity: bool,
+    #[serde(default)]
+    pub commit_sign: bool,
+    pub signing_key: Option<String>,
+    pub github_api_version: Option<String>,
+    #[serde(default)]
+    pub sanitize_description: bool,
+    pub default_commit_message: Option<String>,
+    pub description_template: Option<String>,
+    /// Description templates keyed by language code, picked with `--lang`
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub description_templates: BTreeMap<String, String>,
+    /// Name the initial branch of new repositories is renamed to
+    pub default_branch: Option<String>,
+    /// Issue labels created in every new repository unless `--no-labels` is given
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub default_labels: Vec<Label>,
+    #[serde(default)]
+    pub enable_secret_scanning: bool,
+    #[serde(default)]
+    pub enable_push_protection: bool,
+    /// Approving reviews `--protect` requires, 0-6
+    pub required_reviews: Option<u8>,
+    /// Make `--protect` require a review from a code owner
+    #[serde(default)]
+    pub require_code_owner_reviews: bool,
+    /// Make `--protect` dismiss approvals when new commits are pushed
+    #[serde(default)]
+    pub dismiss_stale_reviews: bool,
+    /// Create new repositories with issues turned off
+    #[serde(default)]
+    pub disable_issues: bool,
+    /// Create new repositories with the wiki turned off
+    #[serde(default)]
+    pub disable_wiki: bool,
+    /// Create new repositories with projects turned off
+    #[serde(default)]
+    pub disable_projects: bool,
+    pub creation_webhook_url: Option<String>,
+    pub symbol_set: Option<String>,
+    pub ca_bundle: Option<String>,
+    pub max_retries: Option<u32>,
+    pub retry_delay_ms: Option<u64>,
+    /// Seconds before an HTTP request is abandoned
+    pub request_timeout_secs: Option<u64>,
+    /// Refuse edits from `config` and `init` until unlocked
+    #[serde(default)]
+    pub locked: bool,
+    /// Usernames used before the current one, most recent first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub known_usernames: Vec<String>,
+    /// GitHub tokens keyed by host, e.g. `github.com` or an Enterprise host
+    ///
+    /// Only written to the file when the OS keyring is unavailable.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub github_tokens: BTreeMap<String, String>,
+    /// Keyring account holding each host's token
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub keyring_accounts: BTreeMap<String, String>,
+}
+
+/// Settings for `repogen new` with every precedence rule applied
+///
+/// Built by [`Config::effective`] from, lowest to highest: built-in defaults,
+/// the config file, environment variables and command-line flags.
+pub struct Effective {
+    pub token: Option<String>,
+    pub api_version: String,
+    /// `--desc`, or the description template for `--lang`, with `{name}` filled in
+    pub description: Option<String>,
+    pub private: bool,
+    /// Visible to every member of the enterprise; implies `private`
+    pub internal: bool,
+    pub license: Option<String>,
+    pub gitignore: Option<String>,
+    pub gitattributes: Option<String>,
+    /// Name to rename the initial branch to, if it differs
+    pub default_branch: Option<String>,
+    pub sanitize_description: bool,
+    /// Message for post-creation content commits, with `{name}` filled in
+    pub commit_message: String,
+    pub enable_secret_scanning: bool,
+    pub enable_push_protection: bool,
+    /// Approving reviews `--protect` requires
+    pub required_reviews: u8,
+    pub require_code_owner_reviews: bool,
+    pub dismiss_stale_reviews: bool,
+    pub disable_issues: bool,
+    pub disable_wiki: bool,
+    pub disable_projects: bool,
+    /// Secondary rate limit retries, or `None` to fail on the first rejection
+    pub retry: Option<RetryPolicy>,
+    pub recurse_submodules: bool,
+    /// Clone from the SSH URL instead of HTTPS
+    pub clone_ssh: bool,
+    /// Shell command run inside the clone
+    pub post_clone_command: Option<String>,
+    /// Clone the repository once it's created
+    pub auto_clone: bool,
+    /// Open the repository in the browser once it's created
+    pub auto_open: bool,
+    /// Leave the cloned repository's git config untouched
+    pub skip_git_config: bool,
+    pub commit_sign: bool,
+}
+
+/// A setting whose effective value comes from outside the config file
+pub struct Override {
+    pub field: &'static str,
+    pub source: String,
 }
 
 impl Config {
@@ -27,45 +357,928 @@ impl Config {
         Ok(home.join(".config").join("repogen"))
     }
 
-    /// Get the config file path
+    /// Get the config file path of the active profile
     pub fn config_file() -> Result<PathBuf> {
-        Ok(Self::config_dir()?.join("config.toml"))
+        Self::profile_file(active_profile())
+    }
+
+    /// Get the config file path of a profile
+    pub fn profile_file(profile: &str) -> Result<PathBuf> {
+        Ok(Self::profiles_dir()?.join(format!("{}.toml", profile)))
+    }
+
+    /// Get the directory holding one config file per profile
+    pub fn profiles_dir() -> Result<PathBuf> {
+        Ok(Self::config_dir()?.join("profiles"))
+    }
+
+    /// Get the path of the copy kept from before the last save
+    pub fn backup_file() -> Result<PathBuf> {
+        Ok(Self::config_file()?.with_extension("toml.bak"))
+    }
+
+    /// Names of the profiles that have a config file, sorted
+    pub fn list_profiles() -> Result<Vec<String>> {
+        let entries = match fs::read_dir(Self::profiles_dir()?) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e).context("Failed to read profiles directory"),
+        };
+
+        let mut profiles: Vec<String> = entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "toml"))
+            .filter_map(|path| {
+                path.file_stem()
+                    .map(|stem| stem.to_string_lossy().into_owned())
+            })
+            .collect();
+        profiles.sort();
+
+        Ok(profiles)
+    }
+
+    /// The profile saved by `repogen profile switch`, if any
+    fn saved_profile() -> Option<String> {
+        let content = fs::read_to_string(Self::config_dir().ok()?.join("active_profile")).ok()?;
+        let profile = content.trim();
+
+        validate_profile_name(profile)
+            .ok()
+            .map(|_| profile.to_string())
+    }
+
+    /// Make `profile` the one used when `--profile` isn't given
+    pub fn save_active_profile(profile: &str) -> Result<()> {
+        validate_profile_name(profile)?;
+
+        let config_dir = Self::config_dir()?;
+        fs::create_dir_all(&config_dir).context("Failed to create config directory")?;
+        fs::write(config_dir.join("active_profile"), format!("{}\n", profile))
+            .context("Failed to save active profile")
+    }
+
+    /// Move `config.toml` from before profiles existed into the default profile
+    fn migrate_legacy_file() -> Result<()> {
+        let config_dir = Self::config_dir()?;
+        let legacy_file = config_dir.join("config.toml");
+        let default_file = Self::profile_file(DEFAULT_PROFILE)?;
+
+        if !legacy_file.exists() || default_file.exists() {
+            return Ok(());
+        }
+
+        fs::create_dir_all(Self::profiles_dir()?).context("Failed to create profiles directory")?;
+        fs::rename(&legacy_file, &default_file).context("Failed to move config into profiles")?;
+
+        let legacy_backup = config_dir.join("config.toml.bak");
+        if legacy_backup.exists() {
+            let _ = fs::rename(&legacy_backup, default_file.with_extension("toml.bak"));
+        }
+
+        // Runs before the symbol set is chosen, so no decorative symbols
+        eprintln!(
+            "Moved {} to {} (profile '{}')",
+            legacy_file.display(),
+            default_file.display(),
+            DEFAULT_PROFILE
+        );
+        Ok(())
+    }
+
+    /// Get the GitHub host whose token is used
+    pub fn github_host(&self) -> &str {
+        self.github_host.as_deref().unwrap_or(DEFAULT_GITHUB_HOST)
+    }
+
+    /// Get the stored token for the active GitHub host
+    pub fn token(&self) -> Option<&str> {
+        self.github_tokens
+            .get(self.github_host())
+            .map(String::as_str)
+    }
+
+    /// Get the REST API base URL, falling back to github.com's
+    pub fn api_base_url(&self) -> &str {
+        self.api_base_url
+            .as_deref()
+            .unwrap_or(github::DEFAULT_API_BASE_URL)
+    }
+
+    /// Get the GitHub API version to request, falling back to the default
+    pub fn api_version(&self) -> &str {
+        self.github_api_version
+            .as_deref()
+            .unwrap_or(DEFAULT_GITHUB_API_VERSION)
+    }
+
+    /// How long to wait on an HTTP request, ignoring a zero timeout
+    pub fn request_timeout(&self) -> Duration {
+        let secs = self
+            .request_timeout_secs
+            .filter(|secs| *secs > 0)
+            .unwrap_or(DEFAULT_REQUEST_TIMEOUT_SECS);
+        Duration::from_secs(secs)
+    }
+
+    /// Resolve the secondary rate limit retry policy
+    ///
+    /// Flag values win over config values; both are clamped to the accepted limits.
+    pub fn retry_policy(
+        &self,
+        max_retries: Option<u32>,
+        retry_delay_ms: Option<u64>,
+    ) -> RetryPolicy {
+        let max_retries = max_retries
+            .or(self.max_retries)
+            .unwrap_or(DEFAULT_MAX_RETRIES)
+            .min(MAX_RETRIES_LIMIT);
+        let delay_ms = retry_delay_ms
+            .or(self.retry_delay_ms)
+            .unwrap_or(DEFAULT_RETRY_DELAY_MS)
+            .clamp(*RETRY_DELAY_MS_RANGE.start(), *RETRY_DELAY_MS_RANGE.end());
+
+        RetryPolicy {
+            max_retries,
+            delay: Duration::from_millis(delay_ms),
+        }
+    }
+
+    /// Resolve the GitHub token to use for API calls
+    ///
+    /// Precedence: `--token-file` > `REPOGEN_TOKEN_FILE` > `REPOGEN_TOKEN` >
+    /// `GITHUB_TOKEN` > the config file's token for the active `github_host`.
+    /// Tokens from the environment are never saved to the config file.
+    pub fn resolve_token(&self, token_file: Option<&Path>) -> Result<Option<String>> {
+        self.resolve_token_with(token_file, |key| env::var_os(key))
+    }
+
+    /// [`Config::resolve_token`] reading environment variables through `env`
+    ///
+    /// Refuses the placeholder saved by a dry run so it never reaches GitHub.
+    fn resolve_token_with(
+        &self,
+        token_file: Option<&Path>,
+        env: impl Fn(&str) -> Option<OsString>,
+    ) -> Result<Option<String>> {
+        if let Some(path) = token_file {
+            return Self::read_token_file(path).map(Some);
+        }
+
+        if let Some(path) = env(TOKEN_FILE_ENV).filter(|p| !p.is_empty()) {
+            return Self::read_token_file(Path::new(&path)).map(Some);
+        }
+
+        if let Some((_, token)) = Self::env_token(&env) {
+            return Ok(Some(token));
+        }
+
+        match self.token() {
+            Some(DRY_RUN_TOKEN) => Err(anyhow!(
+                "The stored GitHub token is a placeholder from `repogen init --auth --dry-run`. Run `repogen init --auth` to authenticate for real."
+            )),
+            token => Ok(token.map(str::to_string)),
+        }
+    }
+
+    /// The first non-empty token variable from [`TOKEN_ENVS`], with its name
+    pub fn env_token(env: impl Fn(&str) -> Option<OsString>) -> Option<(&'static str, String)> {
+        TOKEN_ENVS.iter().find_map(|name| {
+            let token = env(name)?.to_string_lossy().trim().to_string();
+            (!token.is_empty()).then_some((*name, token))
+        })
+    }
+
+    /// Check that the config may be changed
+    ///
+    /// A locked config is only changed with `force`, and a warning is printed
+    /// when that happens.
+    pub fn ensure_unlocked(&self, force: bool) -> Result<()> {
+        if !self.locked {
+            return Ok(());
+        }
+
+        if force {
+            errln!("⚠️  Configuration is locked; changing it anyway (--force)");
+            return Ok(());
+        }
+
+        Err(anyhow!(
+            "Configuration is locked. Run `repogen config --unlock` first, or pass --force."
+        ))
+    }
+
+    /// Whether the stored token is the dry-run placeholder
+    pub fn has_dry_run_token(&self) -> bool {
+        self.token() == Some(DRY_RUN_TOKEN)
+    }
+
+    /// Merge this config with flags and environment into the settings `repogen new` uses
+    ///
+    /// Every precedence rule for `new` lives here; `name` is the repository
+    /// name and `env` looks up environment variables so callers can
+    /// substitute their own.
+    pub fn effective(
+        &self,
+        args: &cli::New,
+        name: &str,
+        global: &cli::GlobalArgs,
+        env: impl Fn(&str) -> Option<OsString>,
+    ) -> Result<Effective> {
+        let token = self.resolve_token_with(global.token_file.as_deref(), env)?;
+        let api_version = global
+            .api_version
+            .clone()
+            .unwrap_or_else(|| self.api_version().to_string());
+
+        // --visibility, then the deprecated --public or --private, beat the config default
+        let private = match args.visibility.as_deref() {
+            Some(visibility) => visibility != "public",
+            None => requested_privacy(args.private, args.public).unwrap_or(self.default_private),
+        };
+
+        // An explicit description beats templates; --lang falls back to the default template
+        let description = args.description.clone().or_else(|| {
+            args.lang
+                .as_ref()
+                .and_then(|lang| self.description_templates.get(lang))
+                .or(self.description_template.as_ref())
+                .map(|template| template.replace("{name}", name))
+        });
+
+        let commit_message = args
+            .commit_message
+            .as_deref()
+            .or(self.default_commit_message.as_deref())
+            .unwrap_or(DEFAULT_COMMIT_MESSAGE)
+            .replace("{name}", name);
+
+        // Retrying is opt-in: the flag, or a non-zero retry count from either source
+        let retry_enabled = args.retry_on_secondary_limit
+            || args.max_retries.or(self.max_retries).is_some_and(|n| n > 0);
+        let retry = retry_enabled.then(|| self.retry_policy(args.max_retries, args.retry_delay_ms));
+
+        // Explicit identity and signing flags still win over the skip_git_config default
+        let skip_git_config = args.no_git_config
+            || (self.skip_git_config
+                && args.author_name.is_none()
+                && args.author_email.is_none()
+                && !args.commit_sign);
+
+        Ok(Effective {
+            token,
+            api_version,
+            description,
+            private,
+            internal: args.visibility.as_deref() == Some("internal"),
+            license: Self::pick_template(args.license.as_deref(), self.default_license.as_deref()),
+            gitignore: Self::pick_template(
+                args.gitignore.as_deref(),
+                self.default_gitignore.as_deref(),
+            ),
+            gitattributes: Self::pick_template(
+                args.gitattributes.as_deref(),
+                self.default_gitattributes.as_deref(),
+            ),
+            default_branch: args
+                .default_branch
+                .clone()
+                .or_else(|| self.default_branch.clone()),
+            sanitize_description: self.sanitize_description,
+            commit_message,
+            enable_secret_scanning: args.enable_secret_scanning || self.enable_secret_scanning,
+            enable_push_protection: args.enable_push_protection || self.enable_push_protection,
+            required_reviews: args
+                .require_reviews
+                .or(self.required_reviews)
+                .unwrap_or(DEFAULT_REQUIRED_REVIEWS)
+                .min(MAX_REQUIRED_REVIEWS),
+            require_code_owner_reviews: args.require_code_owner_reviews
+                || self.require_code_owner_reviews,
+            dismiss_stale_reviews: args.dismiss_stale_reviews || self.dismiss_stale_reviews,
+            disable_issues: args.no_issues || self.disable_issues,
+            disable_wiki: args.no_wiki || self.disable_wiki,
+            disable_projects: args.no_projects || self.disable_projects,
+            retry,
+            recurse_submodules: args.recurse_submodules || self.clone_recurse_submodules,
+            clone_ssh: args.ssh || self.clone_over_ssh(),
+            post_clone_command: args
+                .post_clone
+                .clone()
+                .or_else(|| self.post_clone_command.clone()),
+            auto_clone: (self.auto_clone || args.clone) && !args.no_clone,
+            auto_open: (self.auto_open || args.open) && !args.no_open,
+            skip_git_config,
+            commit_sign: args.commit_sign || self.commit_sign,
+        })
+    }
+
+    /// A flag value wins over the config default; "none" in the flag means no template
+    fn pick_template(flag: Option<&str>, default: Option<&str>) -> Option<String> {
+        match flag {
+            Some(value) if value.eq_ignore_ascii_case("none") => None,
+            Some(value) => Some(value.to_string()),
+            None => default.map(str::to_string),
+        }
+    }
+
+    /// Replace file values with environment and flag overrides
+    ///
+    /// Follows the same precedence as the commands themselves and returns
+    /// which fields changed and where their values came from.
+    pub fn apply_overrides(&mut self, global: &cli::GlobalArgs) -> Result<Vec<Override>> {
+        let mut overrides = Vec::new();
+
+        if let Some(path) = &global.token_file {
+            self.set_github_token(Self::read_token_file(path)?);
+            overrides.push(Override {
+                field: "github_token",
+                source: "--token-file".to_string(),
+            });
+        } else if let Some(path) = env::var_os(TOKEN_FILE_ENV).filter(|p| !p.is_empty()) {
+            self.set_github_token(Self::read_token_file(Path::new(&path))?);
+            overrides.push(Override {
+                field: "github_token",
+                source: TOKEN_FILE_ENV.to_string(),
+            });
+        } else if let Some((name, token)) = Self::env_token(|key| env::var_os(key)) {
+            self.set_github_token(token);
+            overrides.push(Override {
+                field: "github_token",
+                source: name.to_string(),
+            });
+        }
+
+        if let Some(version) = &global.api_version {
+            self.github_api_version = Some(version.clone());
+            overrides.push(Override {
+                field: "github_api_version",
+                source: "--api-version".to_string(),
+            });
+        }
+
+        if let Some(url) = &global.api_url {
+            self.api_base_url = Some(url.clone());
+            overrides.push(Override {
+                field: "api_base_url",
+                source: "--api-url".to_string(),
+            });
+        }
+
+        Ok(overrides)
+    }
+
+    /// Read a token from a file such as a mounted Docker secret
+    fn read_token_file(path: &Path) -> Result<String> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read token file: {}", path.display()))?;
+        let token = content.trim();
+
+        if token.is_empty() {
+            return Err(anyhow!("Token file is empty: {}", path.display()));
+        }
+
+        Ok(token.to_string())
     }
 
     /// Load config from file, or create default if it doesn't exist
     pub fn load() -> Result<Self> {
-        let config_file = Self::config_file()?;
+        let mut config = Self::read_file()?;
+        config.load_keyring_tokens();
+        Ok(config)
+    }
+
+    /// Load config from file without reading tokens from the OS keyring
+    ///
+    /// For settings needed before any command runs, such as the symbol set.
+    pub fn read_file() -> Result<Self> {
+        Self::read_path(&Self::config_file()?)
+    }
 
+    /// Load a profile's config from file without reading tokens from the OS keyring
+    pub fn read_profile(profile: &str) -> Result<Self> {
+        Self::read_path(&Self::profile_file(profile)?)
+    }
+
+    fn read_path(config_file: &Path) -> Result<Self> {
         if !config_file.exists() {
             return Ok(Self::default());
         }
 
-        let content = fs::read_to_string(&config_file).context("Failed to read config file")?;
+        let content = fs::read_to_string(config_file).context("Failed to read config file")?;
 
-        let config: Config = toml::from_str(&content).context("Failed to parse config file")?;
+        let mut config: Config = toml::from_str(&content).context("Failed to parse config file")?;
+        config.migrate_legacy_token();
 
         Ok(config)
     }
 
+    /// Merge the config file at `path` over this config
+    ///
+    /// Fields the file sets win and fields it leaves out keep their current
+    /// value; tables such as `github_tokens` are merged entry by entry.
+    /// Nothing changes unless the result is a valid config. Returns the tokens
+    /// the file contained, keyed by host.
+    pub fn merge_file(&mut self, path: &Path) -> Result<BTreeMap<String, String>> {
+        let invalid = |e: &dyn std::fmt::Display| {
+            anyhow!("{} is not a valid repogen config: {}", path.display(), e)
+        };
+
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let mut table: toml::Table = toml::from_str(&content).map_err(|e| invalid(&e))?;
+
+        // Keyring entries belong to the machine the file came from, so this
+        // profile's own entries are kept
+        table.remove("keyring_accounts");
+
+        // Older configs hold a single github.com token
+        if let Some(token) = table.remove("github_token") {
+            let tokens = table
+                .entry("github_tokens")
+                .or_insert_with(|| toml::Value::Table(toml::Table::new()));
+            if let toml::Value::Table(tokens) = tokens {
+                tokens.entry(DEFAULT_GITHUB_HOST).or_insert(token);
+            }
+        }
+        let imported_tokens: BTreeMap<String, String> = match table.get("github_tokens") {
+            Some(tokens) => tokens.clone().try_into().map_err(|e| invalid(&e))?,
+            None => BTreeMap::new(),
+        };
+
+        let mut merged = toml::Table::try_from(&*self).context("Failed to serialize config")?;
+        for (key, value) in table {
+            match (merged.get_mut(&key), value) {
+                (Some(toml::Value::Table(current)), toml::Value::Table(entries)) => {
+                    current.extend(entries)
+                }
+                (_, value) => {
+                    merged.insert(key, value);
+                }
+            }
+        }
+
+        *self = merged.try_into().map_err(|e| invalid(&e))?;
+
+        Ok(imported_tokens)
+    }
+
+    /// Fill `github_tokens` from the OS keyring entries listed in `keyring_accounts`
+    fn load_keyring_tokens(&mut self) {
+        for (host, account) in &self.keyring_accounts {
+            if self.github_tokens.contains_key(host) {
+                continue;
+            }
+
+            match secrets::load(account) {
+                Ok(token) => {
+                    self.github_tokens.insert(host.clone(), token);
+                }
+                Err(e) => errln!(
+                    "⚠️  Could not read the {} token from the OS keyring: {}",
+                    host,
+                    e.root_cause()
+                ),
+            }
+        }
+    }
+
+    /// Remove every token this config keeps in the OS keyring
+    pub fn forget_keyring_tokens(&self) {
+        for account in self.keyring_accounts.values() {
+            if let Err(e) = secrets::delete(account) {
+                errln!(
+                    "⚠️  Could not remove token from the OS keyring: {}",
+                    e.root_cause()
+                );
+            }
+        }
+    }
+
+    /// Move tokens into the OS keyring, returning the config as it should be written
+    ///
+    /// Tokens the keyring can't take stay in the returned config so they are
+    /// written to the file as before. Keyring entries that are no longer used
+    /// are removed.
+    fn with_tokens_in_keyring(&self) -> Self {
+        let mut on_disk = self.clone();
+        on_disk.github_tokens.clear();
+        on_disk.keyring_accounts.clear();
+
+        for (host, token) in &self.github_tokens {
+            let account = secrets::account(self.github_username.as_deref(), host);
+
+            match secrets::store(&account, token) {
+                Ok(()) => {
+                    on_disk.keyring_accounts.insert(host.clone(), account);
+                }
+                Err(e) => {
+                    errln!(
+                        "⚠️  OS keyring unavailable ({}); storing the {} token in the config file instead",
+                        e.root_cause(),
+                        host
+                    );
+                    on_disk.github_tokens.insert(host.clone(), token.clone());
+                }
+            }
+        }
+
+        for (host, account) in &self.keyring_accounts {
+            if on_disk.keyring_accounts.get(host) != Some(account)
+                && let Err(e) = secrets::delete(account)
+            {
+                errln!(
+                    "⚠️  Could not remove old {} token from the OS keyring: {}",
+                    host,
+                    e.root_cause()
+                );
+            }
+        }
+
+        on_disk
+    }
+
+    /// Move a token from the old single `github_token` field into the host map
+    ///
+    /// Older configs only ever talked to github.com, so that's where it goes.
+    /// An existing github.com entry wins over the legacy field.
+    fn migrate_legacy_token(&mut self) {
+        if let Some(token) = self.github_token.take() {
+            self.github_tokens
+                .entry(DEFAULT_GITHUB_HOST.to_string())
+                .or_insert(token);
+        }
+    }
+
+    /// Load config, offering to repair the file if it can't be parsed
+    ///
+    /// A typo in the TOML would otherwise mean running with defaults. When a
+    /// terminal is attached, the user can fix the file in an editor, restore
+    /// the backup, or start fresh; otherwise the parse error is returned.
+    pub fn load_or_recover() -> Result<Self> {
+        loop {
+            let error = match Self::load() {
+                Ok(config) => return Ok(config),
+                Err(e) if e.downcast_ref::<toml::de::Error>().is_some() => e,
+                Err(e) => return Err(e),
+            };
+
+            if !console::user_attended() {
+                return Err(error);
+            }
+
+            let config_file = Self::config_file()?;
+            let backup_file = Self::backup_file()?;
+
+            errln!(
+                "\n❌ Your config file could not be parsed: {}",
+                config_file.display()
+            );
+            if let Some(parse_error) = error.downcast_ref::<toml::de::Error>() {
+                errln!("{}", parse_error);
+            }
+
+            let mut options = vec!["Open it in an editor to fix it"];
+            if backup_file.exists() {
+                options.push("Restore the backup from before the last save");
+            }
+            let start_fresh = format!(
+                "Start fresh (the broken file is kept as {}.toml.corrupt)",
+                active_profile()
+            );
+            options.push(&start_fresh);
+            options.push("Quit");
+
+            let selection = Select::with_theme(&ColorfulTheme::default())
+                .with_prompt("How would you like to recover?")
+                .items(&options)
+                .default(0)
+                .interact()
+                .unwrap();
+
+            match options[selection] {
+                "Open it in an editor to fix it" => Self::open_in_editor(&config_file)?,
+                "Restore the backup from before the last save" => {
+                    fs::copy(&backup_file, &config_file)
+                        .context("Failed to restore config backup")?;
+                    outln!("✅ Restored {}", backup_file.display());
+                }
+                "Quit" => {
+                    errln!("Fix {} and run repogen again.", config_file.display());
+                    process::exit(1);
+                }
+                _ => {
+                    let corrupt_file = config_file.with_extension("toml.corrupt");
+                    fs::rename(&config_file, &corrupt_file)
+                        .context("Failed to move broken config file aside")?;
+                    outln!("✅ Moved broken config to {}", corrupt_file.display());
+                    return Ok(Self::default());
+                }
+            }
+        }
+    }
+
+    /// Open a file in `$VISUAL`/`$EDITOR` and wait for the editor to exit
+    fn open_in_editor(path: &Path) -> Result<()> {
+        let editor = env::var("VISUAL")
+            .or_else(|_| env::var("EDITOR"))
+            .unwrap_or_else(|_| {
+                if cfg!(windows) {
+                    "notepad".to_string()
+                } else {
+                    "vi".to_string()
+                }
+            });
+
+        // Editors like `code --wait` come with arguments
+        let mut parts = editor.split_whitespace();
+        let program = parts.next().ok_or_else(|| anyhow!("$EDITOR is empty"))?;
+
+        let status = Command::new(program)
+            .args(parts)
+            .arg(path)
+            .status()
+            .with_context(|| format!("Failed to launch editor '{}'", editor))?;
+
+        if !status.success() {
+            return Err(anyhow!("Editor '{}' exited with {}", editor, status));
+        }
+
+        Ok(())
+    }
+
+    /// Check semantic constraints that TOML parsing can't catch
+    ///
+    /// Returns human-readable warnings rather than errors so a hand-edited or
+    /// outdated config still loads.
+    pub fn validate(&self) -> Vec<String> {
+        let mut warnings = Vec::new();
+
+        if let Some(license) = &self.default_license
+            && !LICENSE_OPTIONS[1..].contains(&license.as_str())
+        {
+            warnings.push(format!(
+                "default_license '{}' is not a known license (expected one of: {})",
+                license,
+                LICENSE_OPTIONS[1..].join(", ")
+            ));
+        }
+
+        if let Some(gitignore) = &self.default_gitignore {
+            let known = gitignore::known_templates();
+            for template in gitignore::split_templates(gitignore) {
+                if !known.contains(&template) {
+                    warnings.push(format!(
+                        "default_gitignore '{}' is not a known .gitignore template",
+                        template
+                    ));
+                }
+            }
+        }
+
+        if let Some(gitattributes) = &self.default_gitattributes
+            && !GITATTRIBUTES_OPTIONS[1..].contains(&gitattributes.as_str())
+        {
+            warnings.push(format!(
+                "default_gitattributes '{}' is not a built-in .gitattributes template (expected one of: {})",
+                gitattributes,
+                GITATTRIBUTES_OPTIONS[1..].join(", ")
+            ));
+        }
+
+        if let Some(editor) = &self.preferred_editor
+            && !EDITOR_OPTIONS[1..].contains(&editor.as_str())
+        {
+            warnings.push(format!(
+                "preferred_editor '{}' is not a supported editor (expected one of: {})",
+                editor,
+                EDITOR_OPTIONS[1..].join(", ")
+            ));
+        }
+
+        for label in &self.default_labels {
+            if !is_label_color(&label.color) {
+                warnings.push(format!(
+                    "default_labels color '{}' for '{}' should be six hex digits, like d73a4a",
+                    label.color, label.name
+                ));
+            }
+        }
+
+        if let Some(api_base_url) = &self.api_base_url
+            && !reqwest::Url::parse(api_base_url)
+                .is_ok_and(|url| matches!(url.scheme(), "http" | "https"))
+        {
+            warnings.push(format!(
+                "api_base_url '{}' is not an http(s) URL",
+                api_base_url
+            ));
+        }
+
+        if let Some(ca_bundle) = &self.ca_bundle
+            && !Self::expand_home(ca_bundle).is_file()
+        {
+            warnings.push(format!("ca_bundle '{}' does not exist", ca_bundle));
+        }
+
+        if let Some(symbol_set) = &self.symbol_set
+            && !SYMBOL_SET_OPTIONS[1..].contains(&symbol_set.as_str())
+        {
+            warnings.push(format!(
+                "symbol_set '{}' is not a known symbol set (expected one of: {})",
+                symbol_set,
+                SYMBOL_SET_OPTIONS[1..].join(", ")
+            ));
+        }
+
+        if let Some(protocol) = &self.clone_protocol
+            && !CLONE_PROTOCOL_OPTIONS.contains(&protocol.as_str())
+        {
+            warnings.push(format!(
+                "clone_protocol '{}' is not a known protocol (expected one of: {})",
+                protocol,
+                CLONE_PROTOCOL_OPTIONS.join(", ")
+            ));
+        }
+
+        if let Some(dir) = &self.clone_directory
+            && !Self::expand_home(dir).is_dir()
+        {
+            warnings.push(format!(
+                "clone_directory '{}' does not exist (it will be created on the next clone)",
+                dir
+            ));
+        }
+
+        if let Some(max_retries) = self.max_retries
+            && max_retries > MAX_RETRIES_LIMIT
+        {
+            warnings.push(format!(
+                "max_retries {} is above the limit of {} (using {})",
+                max_retries, MAX_RETRIES_LIMIT, MAX_RETRIES_LIMIT
+            ));
+        }
+
+        if let Some(required_reviews) = self.required_reviews
+            && required_reviews > MAX_REQUIRED_REVIEWS
+        {
+            warnings.push(format!(
+                "required_reviews {} is above GitHub's limit of {} (using {})",
+                required_reviews, MAX_REQUIRED_REVIEWS, MAX_REQUIRED_REVIEWS
+            ));
+        }
+
+        if self.request_timeout_secs == Some(0) {
+            warnings.push(format!(
+                "request_timeout_secs must be at least 1 (using {})",
+                DEFAULT_REQUEST_TIMEOUT_SECS
+            ));
+        }
+
+        if let Some(delay) = self.retry_delay_ms
+            && !RETRY_DELAY_MS_RANGE.contains(&delay)
+        {
+            warnings.push(format!(
+                "retry_delay_ms {} is outside {}-{} (clamped)",
+                delay,
+                RETRY_DELAY_MS_RANGE.start(),
+                RETRY_DELAY_MS_RANGE.end()
+            ));
+        }
+
+        if let Some(version) = &self.github_api_version
+            && !Self::is_api_version_format(version)
+        {
+            warnings.push(format!(
+                "github_api_version '{}' should be a date like {}",
+                version, DEFAULT_GITHUB_API_VERSION
+            ));
+        }
+
+        warnings
+    }
+
+    /// Print validation warnings when a user is at the terminal
+    pub fn print_warnings(&self) {
+        if !console::user_attended() {
+            return;
+        }
+
+        for warning in self.validate() {
+            errln!("⚠️  Config: {}", warning);
+        }
+    }
+
+    /// Expand a leading `~` to the home directory
+    pub fn expand_home(path: &str) -> PathBuf {
+        if let Some(rest) = path.strip_prefix("~/")
+            && let Some(home) = home_dir()
+        {
+            return home.join(rest);
+        }
+
+        if path == "~"
+            && let Some(home) = home_dir()
+        {
+            return home;
+        }
+
+        PathBuf::from(path)
+    }
+
+    /// Check that an API version looks like `YYYY-MM-DD`
+    fn is_api_version_format(version: &str) -> bool {
+        let parts: Vec<&str> = version.split('-').collect();
+        parts.len() == 3
+            && [4, 2, 2]
+                .iter()
+                .zip(&parts)
+                .all(|(len, part)| part.len() == *len && part.chars().all(|c| c.is_ascii_digit()))
+    }
+
     /// Save config to file
     pub fn save(&self) -> Result<()> {
-        let config_dir = Self::config_dir()?;
+        let profiles_dir = Self::profiles_dir()?;
         let config_file = Self::config_file()?;
 
         // Create config directory if it doesn't exist
-        fs::create_dir_all(&config_dir).context("Failed to create config directory")?;
+        fs::create_dir_all(&profiles_dir).context("Failed to create config directory")?;
+
+        let toml_string = toml::to_string_pretty(&self.with_tokens_in_keyring())
+            .context("Failed to serialize config")?;
 
-        let toml_string = toml::to_string_pretty(self).context("Failed to serialize config")?;
+        // Keep the previous version around in case this save needs undoing
+        if config_file.exists() {
+            fs::copy(&config_file, Self::backup_file()?)
+                .context("Failed to back up config file")?;
+        }
 
-        fs::write(&config_file, toml_string).context("Failed to write config file")?;
+        Self::write_atomically(&config_file, &toml_string)?;
 
-        println!("📁 Config saved to: {}", config_file.display());
+        outln!("📁 Config saved to: {}", config_file.display());
         Ok(())
     }
 
-    /// Set GitHub token
+    /// Write this config to `path` for sharing or backup
+    ///
+    /// Tokens, the OAuth client ID and the creation webhook URL (which often
+    /// embeds a secret) are left out unless `include_secrets` is set. Keyring
+    /// entries only mean something on this machine, so they're always left out.
+    pub fn export(&self, path: &Path, include_secrets: bool) -> Result<()> {
+        let mut exported = self.clone();
+        exported.keyring_accounts.clear();
+        if !include_secrets {
+            exported.github_tokens.clear();
+            exported.oauth_client_id = None;
+            exported.creation_webhook_url = None;
+        }
+
+        let toml_string =
+            toml::to_string_pretty(&exported).context("Failed to serialize config")?;
+        Self::write_atomically(path, &toml_string)
+    }
+
+    /// Replace `path` with `contents` without ever leaving a partial file
+    ///
+    /// Writes and syncs a temporary file in the same directory, then renames it
+    /// over the target. On Unix the file keeps its current permissions, or gets
+    /// 0600 when new, since it may hold tokens.
+    fn write_atomically(path: &Path, contents: &str) -> Result<()> {
+        let mut temp_name = path.file_name().unwrap_or_default().to_os_string();
+        temp_name.push(".tmp");
+        let temp_path = path.with_file_name(temp_name);
+
+        let write = || -> std::io::Result<()> {
+            let mut file = fs::File::create(&temp_path)?;
+            #[cfg(unix)]
+            {
+                use std::os::unix::fs::PermissionsExt;
+                let mode = fs::metadata(path).map_or(0o600, |m| m.permissions().mode() & 0o777);
+                file.set_permissions(fs::Permissions::from_mode(mode))?;
+            }
+            file.write_all(contents.as_bytes())?;
+            file.sync_all()
+        };
+
+        if let Err(e) = write() {
+            let _ = fs::remove_file(&temp_path);
+            return Err(e).context("Failed to write config file");
+        }
+
+        fs::rename(&temp_path, path).context("Failed to replace config file")
+    }
+
+    /// Set the GitHub token for the active host
     pub fn set_github_token(&mut self, token: String) {
-        self.github_token = Some(token);
+        self.github_tokens
+            .insert(self.github_host().to_string(), token);
+    }
+
+    /// Remove the GitHub token for the active host
+    pub fn clear_github_token(&mut self) {
+        let host = self.github_host().to_string();
+        self.github_tokens.remove(&host);
     }
 
     /// Set user profile information
@@ -75,11 +1288,110 @@ impl Config {
         name: Option<String>,
         email: Option<String>,
     ) {
-        self.github_username = Some(username);
+        self.set_github_username(username);
         self.user_name = name;
         self.user_email = email;
     }
 
+    /// Set the GitHub username, remembering the previous one for prompt history
+    pub fn set_github_username(&mut self, username: String) {
+        if let Some(previous) = self.github_username.take()
+            && previous != username
+        {
+            self.known_usernames.retain(|known| *known != previous);
+            self.known_usernames.insert(0, previous);
+            self.known_usernames.truncate(MAX_KNOWN_USERNAMES);
+        }
+
+        self.known_usernames.retain(|known| *known != username);
+        self.github_username = Some(username);
+    }
+
+    /// Usernames to offer in prompts: the current one first, then earlier ones
+    pub fn usernames(&self) -> Vec<String> {
+        self.github_username
+            .iter()
+            .chain(&self.known_usernames)
+            .cloned()
+            .collect()
+    }
+
+    /// Set a field from its name and a string value, as given to `config --set`
+    ///
+    /// Booleans accept true/false, yes/no, on/off and 1/0. An empty value
+    /// clears optional fields.
+    pub fn set_value(&mut self, key: &str, value: &str) -> Result<()> {
+        let text = Some(value.trim().to_string()).filter(|value| !value.is_empty());
+        let flag = || parse_bool(key, value);
+
+        match key {
+            "github_host" => self.github_host = text,
+            "api_base_url" => self.api_base_url = text,
+            "github_username" => self.github_username = text,
+            "user_name" => self.user_name = text,
+            "user_email" => self.user_email = text,
+            "default_private" => self.default_private = flag()?,
+            "default_license" => self.default_license = text,
+            "default_gitignore" => self.default_gitignore = text,
+            "default_gitattributes" => self.default_gitattributes = text,
+            "preferred_editor" => self.preferred_editor = text,
+            "oauth_client_id" => self.oauth_client_id = text,
+            "auto_open" => self.auto_open = flag()?,
+            "auto_clone" => self.auto_clone = flag()?,
+            "clone_directory" => self.clone_directory = text,
+            "clone_protocol" => self.clone_protocol = text,
+            "clone_recurse_submodules" => self.clone_recurse_submodules = flag()?,
+            "post_clone_command" => self.post_clone_command = text,
+            "skip_git_config" => self.skip_git_config = flag()?,
+            "set_local_git_identity" => self.set_local_git_identity = flag()?,
+            "commit_sign" => self.commit_sign = flag()?,
+            "signing_key" => self.signing_key = text,
+            "github_api_version" => self.github_api_version = text,
+            "sanitize_description" => self.sanitize_description = flag()?,
+            "default_commit_message" => self.default_commit_message = text,
+            "description_template" => self.description_template = text,
+            "default_branch" => self.default_branch = text,
+            "enable_secret_scanning" => self.enable_secret_scanning = flag()?,
+            "enable_push_protection" => self.enable_push_protection = flag()?,
+            "required_reviews" => {
+                let reviews = parse_number(key, text)?;
+                if reviews.is_some_and(|n| n > MAX_REQUIRED_REVIEWS) {
+                    return Err(anyhow!(
+                        "required_reviews must be between 0 and {}",
+                        MAX_REQUIRED_REVIEWS
+                    ));
+                }
+                self.required_reviews = reviews;
+            }
+            "require_code_owner_reviews" => self.require_code_owner_reviews = flag()?,
+            "dismiss_stale_reviews" => self.dismiss_stale_reviews = flag()?,
+            "disable_issues" => self.disable_issues = flag()?,
+            "disable_wiki" => self.disable_wiki = flag()?,
+            "disable_projects" => self.disable_projects = flag()?,
+            "creation_webhook_url" => self.creation_webhook_url = text,
+            "symbol_set" => self.symbol_set = text,
+            "ca_bundle" => self.ca_bundle = text,
+            "max_retries" => self.max_retries = parse_number(key, text)?,
+            "request_timeout_secs" => self.request_timeout_secs = parse_number(key, text)?,
+            "retry_delay_ms" => self.retry_delay_ms = parse_number(key, text)?,
+            _ => {
+                let keys: Vec<String> = SETTABLE_KEYS.iter().map(|key| key.to_string()).collect();
+                return Err(match suggest::closest(&keys, key) {
+                    Some(suggestion) => {
+                        anyhow!("Unknown key '{}'. Did you mean '{}'?", key, suggestion)
+                    }
+                    None => anyhow!(
+                        "Unknown key '{}'. Valid keys: {}",
+                        key,
+                        SETTABLE_KEYS.join(", ")
+                    ),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
     /// Set user preferences
     pub fn set_preferences(
         &mut self,
@@ -94,6 +1406,44 @@ impl Config {
         self.preferred_editor = editor;
     }
 
+    /// Set the .gitattributes template committed to new repositories
+    pub fn set_default_gitattributes(&mut self, template: Option<String>) {
+        self.default_gitattributes = template;
+    }
+
+    /// Set which features new repositories are created without: issues, wiki, projects
+    pub fn set_disabled_features(&mut self, [issues, wiki, projects]: [bool; 3]) {
+        self.disable_issues = issues;
+        self.disable_wiki = wiki;
+        self.disable_projects = projects;
+    }
+
+    /// Set whether new repositories are opened in the browser
+    pub fn set_auto_open(&mut self, auto_open: bool) {
+        self.auto_open = auto_open;
+    }
+
+    /// Set the labels created in every new repository
+    pub fn set_default_labels(&mut self, labels: Vec<Label>) {
+        self.default_labels = labels;
+    }
+
+    /// Client ID for the OAuth device flow: the configured one, else the bundled one
+    ///
+    /// The bundled app is registered on github.com, so Enterprise hosts always
+    /// need their own.
+    pub fn oauth_client_id(&self) -> Option<&str> {
+        self.oauth_client_id.as_deref().or_else(|| {
+            BUNDLED_OAUTH_CLIENT_ID
+                .filter(|id| !id.is_empty() && self.github_host() == DEFAULT_GITHUB_HOST)
+        })
+    }
+
+    /// Whether the device flow would use the bundled OAuth app
+    pub fn uses_bundled_oauth_app(&self) -> bool {
+        self.oauth_client_id.is_none() && self.oauth_client_id().is_some()
+    }
+
     /// Set OAuth client ID
     pub fn set_oauth_client_id(&mut self, client_id: String) {
         self.oauth_client_id = Some(client_id);
@@ -104,4 +1454,57 @@ impl Config {
         self.auto_clone = auto_clone;
         self.clone_directory = clone_directory;
     }
+
+    /// Set the protocol repositories are cloned over, `None` for HTTPS
+    pub fn set_clone_protocol(&mut self, protocol: Option<String>) {
+        self.clone_protocol = protocol;
+    }
+
+    /// Whether repositories are cloned from their SSH URL
+    pub fn clone_over_ssh(&self) -> bool {
+        self.clone_protocol.as_deref() == Some("ssh")
+    }
+
+    /// The profile's name and email to write into clones, when that's turned on
+    ///
+    /// Nothing is returned when `skip_git_config` is set, which wins.
+    pub fn local_git_identity(&self) -> (Option<&str>, Option<&str>) {
+        if !self.set_local_git_identity || self.skip_git_config {
+            return (None, None);
+        }
+        (self.user_name.as_deref(), self.user_email.as_deref())
+    }
+}
+
+/// Privacy asked for with `--private`/`--public`, if either was given
+///
+/// `--public` wins when both are set.
+pub fn requested_privacy(private: Option<bool>, public: Option<bool>) -> Option<bool> {
+    if public == Some(true) {
+        Some(false)
+    } else if private == Some(true) {
+        Some(true)
+    } else {
+        None
+    }
+}
+
+/// Read a `config --set` boolean
+fn parse_bool(key: &str, value: &str) -> Result<bool> {
+    match value.trim().to_lowercase().as_str() {
+        "true" | "yes" | "on" | "1" => Ok(true),
+        "false" | "no" | "off" | "0" => Ok(false),
+        _ => Err(anyhow!("{} must be true or false, got '{}'", key, value)),
+    }
+}
+
+/// Read a `config --set` number, where an empty value clears the field
+fn parse_number<T: std::str::FromStr>(key: &str, value: Option<String>) -> Result<Option<T>> {
+    value
+        .map(|value| {
+            value
+                .parse()
+                .map_err(|_| anyhow!("{} must be a whole number, got '{}'", key, value))
+        })
+        .transpose()
 }
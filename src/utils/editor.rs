@@ -0,0 +1,37 @@
+use anyhow::{Context, Result, anyhow};
+use std::path::Path;
+use std::process::Command;
+
+/// The command that launches an editor from `EDITOR_OPTIONS`
+fn command_for(editor: &str) -> Option<&'static str> {
+    match editor {
+        "VS Code" => Some("code"),
+        "Vim" => Some("vim"),
+        "Emacs" => Some("emacs"),
+        "Sublime Text" => Some("subl"),
+        "Atom" => Some("atom"),
+        "IntelliJ" => Some("idea"),
+        _ => None,
+    }
+}
+
+/// Open `path` in the configured `preferred_editor`
+///
+/// Terminal editors take over until they exit; GUI editors return right away.
+pub fn open(editor: &str, path: &Path) -> Result<()> {
+    let program =
+        command_for(editor).ok_or_else(|| anyhow!("'{}' is not a supported editor", editor))?;
+
+    let status = Command::new(program).arg(path).status().with_context(|| {
+        format!(
+            "Failed to launch {}. Is `{}` on your PATH?",
+            editor, program
+        )
+    })?;
+
+    if !status.success() {
+        return Err(anyhow!("{} exited with {}", editor, status));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,225 @@
+use crate::utils::config::Config;
+use anyhow::{Context, Result, anyhow};
+use std::env;
+use std::io::{self, BufReader, Read};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+/// Resolve the directory repositories are cloned into
+///
+/// Uses `clone_directory` from config when set, otherwise the current directory.
+/// The directory is created if it doesn't exist yet.
+pub fn clone_root(config: &Config) -> Result<PathBuf> {
+    let target_dir = if let Some(ref dir) = config.clone_directory {
+        Config::expand_home(dir)
+    } else {
+        env::current_dir().context("Failed to get current directory")?
+    };
+
+    if !target_dir.exists() {
+        std::fs::create_dir_all(&target_dir)
+            .context(format!("Failed to create directory: {:?}", target_dir))?;
+    }
+
+    Ok(target_dir)
+}
+
+/// Run `git clone <url>` inside `target_dir` without asking for credentials
+///
+/// For clones running in the background, such as the workers of `clone --all`,
+/// where nobody could answer a credential prompt; git fails instead. With
+/// `recurse_submodules`, submodules are cloned along with the repository.
+pub fn clone(url: &str, target_dir: &Path, recurse_submodules: bool) -> Result<()> {
+    let output = clone_command(url, target_dir, recurse_submodules, false)
+        .env("GIT_TERMINAL_PROMPT", "0")
+        .output()
+        .context("Failed to execute git clone. Is git installed?")?;
+
+    if !output.status.success() {
+        let error_msg = String::from_utf8_lossy(&output.stderr);
+        return Err(anyhow!("Git clone failed: {}", error_msg.trim()));
+    }
+
+    Ok(())
+}
+
+/// [`clone`], passing each progress line git prints to `on_progress` as it arrives
+///
+/// git redraws its progress with carriage returns, so every update is its own
+/// line here, e.g. `Receiving objects:  45% (450/1000), 2.30 MiB | 1.10 MiB/s`.
+pub fn clone_with_progress(
+    url: &str,
+    target_dir: &Path,
+    recurse_submodules: bool,
+    mut on_progress: impl FnMut(&str),
+) -> Result<()> {
+    let mut child = clone_command(url, target_dir, recurse_submodules, true)
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .context("Failed to execute git clone. Is git installed?")?;
+
+    // Finished lines are kept for the error message; redrawn ones only update progress
+    let mut messages = String::new();
+    let mut line = Vec::new();
+    let mut redrawn = false;
+    if let Some(stderr) = child.stderr.take() {
+        for byte in BufReader::new(stderr).bytes() {
+            let byte = byte.context("Failed to read git clone output")?;
+            if byte != b'\r' && byte != b'\n' {
+                line.push(byte);
+                continue;
+            }
+
+            let text = String::from_utf8_lossy(&line);
+            let text = text.trim();
+            if !text.is_empty() {
+                on_progress(text);
+                if byte == b'\n' && !redrawn {
+                    messages.push_str(text);
+                    messages.push('\n');
+                }
+            }
+            redrawn = byte == b'\r';
+            line.clear();
+        }
+    }
+    messages.push_str(String::from_utf8_lossy(&line).trim());
+
+    let status = child.wait().context("Failed to wait for git clone")?;
+    if !status.success() {
+        return Err(anyhow!("Git clone failed: {}", messages.trim()));
+    }
+
+    Ok(())
+}
+
+/// `git clone` for `url`, run inside `target_dir`
+fn clone_command(
+    url: &str,
+    target_dir: &Path,
+    recurse_submodules: bool,
+    progress: bool,
+) -> Command {
+    let mut command = Command::new("git");
+    command.arg("clone");
+    if recurse_submodules {
+        command.arg("--recurse-submodules");
+    }
+    // git only reports progress to a terminal unless asked
+    if progress {
+        command.arg("--progress");
+    }
+
+    command.arg(url).current_dir(target_dir);
+    command
+}
+
+/// Set a local `git config` value inside a repository
+pub fn set_config(repo_dir: &Path, key: &str, value: &str) -> Result<()> {
+    let output = Command::new("git")
+        .args(["config", key, value])
+        .current_dir(repo_dir)
+        .output()
+        .context("Failed to execute git config. Is git installed?")?;
+
+    if !output.status.success() {
+        let error_msg = String::from_utf8_lossy(&output.stderr);
+        return Err(anyhow!("git config {} failed: {}", key, error_msg.trim()));
+    }
+
+    Ok(())
+}
+
+/// Read a `git config` value as seen from inside a repository
+///
+/// Includes global and system config; returns `None` when the key is unset.
+pub fn get_config(repo_dir: &Path, key: &str) -> Option<String> {
+    let output = Command::new("git")
+        .args(["config", "--get", key])
+        .current_dir(repo_dir)
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    let value = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (!value.is_empty()).then_some(value)
+}
+
+/// Run a git subcommand inside `repo_dir`, returning its trimmed stdout
+fn run(repo_dir: &Path, args: &[&str]) -> Result<String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(repo_dir)
+        .output()
+        .with_context(|| format!("Failed to execute git {}. Is git installed?", args[0]))?;
+
+    if !output.status.success() {
+        let error_msg = String::from_utf8_lossy(&output.stderr);
+        return Err(anyhow!("git {} failed: {}", args[0], error_msg.trim()));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Run `git init` in `dir`
+pub fn init(dir: &Path) -> Result<()> {
+    run(dir, &["init"]).map(|_| ())
+}
+
+/// Whether the repository in `repo_dir` has at least one commit
+pub fn has_commits(repo_dir: &Path) -> bool {
+    run(repo_dir, &["rev-parse", "--verify", "--quiet", "HEAD"]).is_ok()
+}
+
+/// Whether the working tree has anything `git add -A` would stage
+pub fn has_changes(repo_dir: &Path) -> Result<bool> {
+    Ok(!run(repo_dir, &["status", "--porcelain"])?.is_empty())
+}
+
+/// Stage everything and commit it
+pub fn commit_all(repo_dir: &Path, message: &str) -> Result<()> {
+    run(repo_dir, &["add", "-A"])?;
+    run(repo_dir, &["commit", "-m", message]).map(|_| ())
+}
+
+/// Name of the checked-out branch
+pub fn current_branch(repo_dir: &Path) -> Result<String> {
+    run(repo_dir, &["symbolic-ref", "--short", "HEAD"])
+}
+
+/// Rename the checked-out branch, replacing any branch already called `name`
+pub fn rename_branch(repo_dir: &Path, name: &str) -> Result<()> {
+    run(repo_dir, &["branch", "-M", name]).map(|_| ())
+}
+
+/// Whether a remote called `name` is configured
+pub fn has_remote(repo_dir: &Path, name: &str) -> bool {
+    run(repo_dir, &["remote", "get-url", name]).is_ok()
+}
+
+/// Add a remote called `name` pointing at `url`
+pub fn add_remote(repo_dir: &Path, name: &str, url: &str) -> Result<()> {
+    run(repo_dir, &["remote", "add", name, url]).map(|_| ())
+}
+
+/// Push `branch` to `remote` and track it, showing git's progress
+///
+/// Not captured, so git can ask for credentials. Progress goes to stderr.
+pub fn push_upstream(repo_dir: &Path, remote: &str, branch: &str) -> Result<()> {
+    let status = Command::new("git")
+        .args(["push", "-u", remote, branch])
+        .current_dir(repo_dir)
+        .stdout(Stdio::from(io::stderr()))
+        .status()
+        .context("Failed to execute git push. Is git installed?")?;
+
+    if !status.success() {
+        return Err(anyhow!("git push exited with {}", status));
+    }
+
+    Ok(())
+}
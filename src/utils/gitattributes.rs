@@ -0,0 +1,41 @@
+const LF_TEMPLATE: &str = "# Normalize line endings to LF in the repository and working tree
+* text=auto eol=lf
+
+# Windows scripts need CRLF
+*.bat text eol=crlf
+*.cmd text eol=crlf
+*.ps1 text eol=crlf
+
+# Never touch binary files
+*.png binary
+*.jpg binary
+*.jpeg binary
+*.gif binary
+*.ico binary
+*.pdf binary
+*.zip binary
+*.gz binary
+";
+
+const LFS_IMAGES_TEMPLATE: &str = "# Normalize line endings for text files
+* text=auto
+
+# Store images and design files with Git LFS (run `git lfs install` once per machine)
+*.png filter=lfs diff=lfs merge=lfs -text
+*.jpg filter=lfs diff=lfs merge=lfs -text
+*.jpeg filter=lfs diff=lfs merge=lfs -text
+*.gif filter=lfs diff=lfs merge=lfs -text
+*.webp filter=lfs diff=lfs merge=lfs -text
+*.psd filter=lfs diff=lfs merge=lfs -text
+*.tif filter=lfs diff=lfs merge=lfs -text
+*.tiff filter=lfs diff=lfs merge=lfs -text
+";
+
+/// Contents of a built-in `.gitattributes` template, matched ignoring case
+pub fn render(template: &str) -> Option<&'static str> {
+    match template.to_ascii_lowercase().as_str() {
+        "lf" => Some(LF_TEMPLATE),
+        "lfs-images" => Some(LFS_IMAGES_TEMPLATE),
+        _ => None,
+    }
+}
@@ -0,0 +1,912 @@
+use crate::utils::config::Label;
+use crate::utils::http::SendLogged;
+use crate::utils::timing::format_duration;
+use crate::{errln, outln};
+use anyhow::{Context, Result, anyhow};
+use base64::prelude::*;
+use chrono::{Local, TimeZone};
+use console::style;
+use reqwest::StatusCode;
+use reqwest::blocking::{Client, RequestBuilder, Response};
+use reqwest::header::{CONTENT_TYPE, HeaderMap, LINK, RETRY_AFTER};
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use serde_json::json;
+use std::collections::BTreeMap;
+use std::sync::RwLock;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Base URL of the GitHub REST API on github.com
+pub const DEFAULT_API_BASE_URL: &str = "https://api.github.com";
+
+/// REST API base for this run, when it isn't github.com's
+static API_BASE: RwLock<Option<String>> = RwLock::new(None);
+
+/// Longest single pause spent waiting out a secondary rate limit
+const MAX_RETRY_PAUSE: Duration = Duration::from_secs(10 * 60);
+
+/// Warn once fewer API requests than this remain in the rate limit window
+const LOW_RATE_LIMIT: u64 = 10;
+
+/// Longest error body echoed back to the user
+const MAX_ERROR_BODY_LEN: usize = 500;
+
+/// Longest error body written to the `--verbose` log
+const LOGGED_BODY_LEN: usize = 2000;
+
+/// How often and how patiently `send_with_pacing` retries
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    /// Retries after the first attempt; zero fails fast
+    pub max_retries: u32,
+    /// Pause used when GitHub doesn't send `Retry-After` (doubled on each retry)
+    pub delay: Duration,
+}
+
+/// Token scopes repogen needs to create and manage repositories
+pub const REQUIRED_SCOPES: &[&str] = &["repo"];
+
+/// The signed-in user, from `GET /user`
+#[derive(Debug, Deserialize)]
+pub struct GitHubUser {
+    pub login: String,
+    pub name: Option<String>,
+    /// `User` or `Organization`
+    #[serde(rename = "type", default)]
+    pub account_type: Option<String>,
+    /// Only included when the token can read the user's plan
+    pub plan: Option<Plan>,
+}
+
+/// The GitHub plan an account is on
+#[derive(Debug, Deserialize)]
+pub struct Plan {
+    pub name: String,
+}
+
+/// A validated token: who it belongs to and what it may do
+#[derive(Debug)]
+pub struct TokenInfo {
+    pub user: GitHubUser,
+    /// Scopes from `X-OAuth-Scopes`, or `None` for tokens GitHub doesn't report
+    /// scopes for, such as fine-grained personal access tokens
+    pub scopes: Option<Vec<String>>,
+}
+
+impl TokenInfo {
+    /// Required scopes the token doesn't have
+    ///
+    /// Unknown scopes count as present, since they can't be checked.
+    pub fn missing_scopes(&self) -> Vec<&'static str> {
+        let Some(scopes) = &self.scopes else {
+            return Vec::new();
+        };
+
+        REQUIRED_SCOPES
+            .iter()
+            .filter(|required| !scopes.iter().any(|scope| scope == *required))
+            .copied()
+            .collect()
+    }
+}
+
+/// Repository entry returned by the GitHub repository endpoints
+#[derive(Debug, Deserialize)]
+pub struct RepoSummary {
+    pub name: String,
+    pub full_name: String,
+    pub private: bool,
+    pub description: Option<String>,
+    pub clone_url: String,
+    pub ssh_url: String,
+    pub html_url: String,
+    pub created_at: String,
+    #[serde(default)]
+    pub is_template: bool,
+    #[serde(default)]
+    pub archived: bool,
+}
+
+/// Send API requests to `url` instead of github.com, e.g. a GitHub Enterprise Server
+///
+/// Set from `--api-url` or the `api_base_url` config field; `None` goes back to github.com.
+pub fn set_api_base(url: Option<&str>) {
+    *API_BASE.write().unwrap() = url.map(|url| url.trim_end_matches('/').to_string());
+}
+
+/// Base URL of the REST API requests go to
+pub fn api_base() -> String {
+    API_BASE
+        .read()
+        .unwrap()
+        .clone()
+        .unwrap_or_else(|| DEFAULT_API_BASE_URL.to_string())
+}
+
+/// Web address of the GitHub instance behind [`api_base`]
+///
+/// Enterprise Server serves its API under `/api/v3` on the web host, while
+/// github.com uses an `api.` subdomain.
+pub fn web_base() -> String {
+    let api = api_base();
+    match api.strip_suffix("/api/v3") {
+        Some(web) => web.to_string(),
+        None => api.replacen("://api.", "://", 1),
+    }
+}
+
+/// Attach the headers every GitHub API request needs
+pub fn with_headers(request: RequestBuilder, token: &str, api_version: &str) -> RequestBuilder {
+    request
+        .header("Authorization", format!("Bearer {}", token))
+        .header("Accept", "application/vnd.github+json")
+        .header("X-GitHub-Api-Version", api_version)
+}
+
+/// Read the body of a failed response as text fit to show the user
+///
+/// Returns `None` when the body isn't something GitHub would send: invalid
+/// UTF-8 (binary or truncated) or an HTML page from a proxy or WAF in front
+/// of the API. Long bodies are cut off at a sensible length.
+pub fn read_error_body(response: Response) -> (StatusCode, Option<String>) {
+    let status = response.status();
+    let html_content_type = response
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.contains("text/html"));
+
+    let Ok(bytes) = response.bytes() else {
+        return (status, Some("Unknown error".to_string()));
+    };
+    let Ok(text) = std::str::from_utf8(&bytes) else {
+        return (status, None);
+    };
+    log::debug!(
+        "{} response body: {}",
+        status,
+        snippet(text, LOGGED_BODY_LEN)
+    );
+
+    if html_content_type || looks_like_html(text) {
+        return (status, None);
+    }
+
+    let text = text.trim();
+    if text.is_empty() {
+        return (status, Some("Unknown error".to_string()));
+    }
+
+    (status, Some(snippet(text, MAX_ERROR_BODY_LEN)))
+}
+
+/// The first `max` characters of `text`, marking where it was cut
+fn snippet(text: &str, max: usize) -> String {
+    match text.char_indices().nth(max) {
+        Some((cut, _)) => format!("{}…", &text[..cut]),
+        None => text.to_string(),
+    }
+}
+
+/// Build the error for a failed response from its status and body
+pub fn api_error_from(status: StatusCode, body: Option<String>) -> anyhow::Error {
+    match body {
+        Some(text) => anyhow!("GitHub API error ({}): {}", status, text),
+        None => anyhow!(
+            "GitHub returned an unexpected (possibly proxy/HTML) response with status {}",
+            status.as_u16()
+        ),
+    }
+}
+
+/// Turn a failed response into an error
+pub fn api_error(response: Response) -> anyhow::Error {
+    let (status, body) = read_error_body(response);
+    api_error_from(status, body)
+}
+
+/// Heuristic check for an HTML page where JSON was expected
+fn looks_like_html(text: &str) -> bool {
+    let start: String = text.trim_start().chars().take(256).collect();
+    let start = start.to_ascii_lowercase();
+
+    start.starts_with("<!doctype") || start.starts_with("<html") || start.contains("<body")
+}
+
+/// Maximum number of topics GitHub allows on a repository
+pub const MAX_TOPICS: usize = 20;
+
+/// Requests left in the rate limit window, and when the window resets
+///
+/// Read from the `X-RateLimit-*` headers GitHub sends with every API response.
+struct RateLimit {
+    remaining: u64,
+    /// Seconds since the Unix epoch
+    reset: u64,
+}
+
+impl RateLimit {
+    fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        let number = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.parse::<u64>().ok())
+        };
+
+        Some(Self {
+            remaining: number("X-RateLimit-Remaining")?,
+            reset: number("X-RateLimit-Reset")?,
+        })
+    }
+
+    /// When the limit resets, e.g. `in 23m 10s (at 14:05 local time)`
+    fn describe_reset(&self) -> String {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_secs())
+            .unwrap_or(0);
+        let wait = Duration::from_secs(self.reset.saturating_sub(now));
+
+        match Local.timestamp_opt(self.reset as i64, 0).single() {
+            Some(at) => format!(
+                "in {} (at {} local time)",
+                format_duration(wait),
+                at.format("%H:%M")
+            ),
+            None => format!("in {}", format_duration(wait)),
+        }
+    }
+
+    fn exceeded_error(&self) -> anyhow::Error {
+        anyhow!(
+            "GitHub API rate limit reached. It resets {}; try again then.",
+            self.describe_reset()
+        )
+    }
+}
+
+/// Turn a response that used up the rate limit into a friendly error
+///
+/// Also warns when only a few requests remain, so scripts see it coming.
+pub fn check_rate_limit(response: &Response) -> Result<()> {
+    let Some(limit) = RateLimit::from_headers(response.headers()) else {
+        return Ok(());
+    };
+
+    if limit.remaining == 0 && !response.status().is_success() {
+        return Err(limit.exceeded_error());
+    }
+
+    if limit.remaining < LOW_RATE_LIMIT {
+        errln!(
+            "{}",
+            style(format!(
+                "⚠️  Only {} GitHub API requests left; the limit resets {}",
+                limit.remaining,
+                limit.describe_reset()
+            ))
+            .dim()
+        );
+    }
+
+    Ok(())
+}
+
+/// Split `owner/repo` (or a bare `repo`) into owner and repository name
+///
+/// Bare names are resolved against `default_owner`, normally the configured username.
+pub fn split_repo(name: &str, default_owner: Option<&str>) -> Result<(String, String)> {
+    if let Some((owner, repo)) = name.split_once('/') {
+        if owner.is_empty() || repo.is_empty() || repo.contains('/') {
+            return Err(anyhow!("Invalid repository '{}'. Use owner/repo", name));
+        }
+        return Ok((owner.to_string(), repo.to_string()));
+    }
+
+    let owner = default_owner.ok_or_else(|| {
+        anyhow!("No GitHub username configured. Use owner/repo or run `repogen init --meta`.")
+    })?;
+
+    Ok((owner.to_string(), name.to_string()))
+}
+
+/// Check a repository name only uses characters GitHub keeps as-is
+///
+/// Returns a plain message so it can back a `dialoguer` input validator.
+pub fn validate_repo_name(name: &str) -> Result<(), String> {
+    if name.trim().is_empty() {
+        return Err("Repository name cannot be empty".to_string());
+    }
+
+    if !name
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+    {
+        return Err("Use only letters, digits, '-', '_' and '.'".to_string());
+    }
+
+    Ok(())
+}
+
+/// Check a topic against GitHub's naming rules
+///
+/// Topics must be lowercase letters, digits and hyphens, start with a letter
+/// or digit, and be at most 50 characters long.
+pub fn validate_topic(topic: &str) -> Result<()> {
+    let valid = !topic.is_empty()
+        && topic.len() <= 50
+        && !topic.starts_with('-')
+        && topic
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+
+    if valid {
+        Ok(())
+    } else {
+        Err(anyhow!(
+            "Invalid topic '{}'. Topics must be lowercase letters, numbers and hyphens (max 50 characters)",
+            topic
+        ))
+    }
+}
+
+/// Check a full set of topics: each name, and that there aren't too many
+pub fn validate_topics(topics: &[String]) -> Result<()> {
+    for topic in topics {
+        validate_topic(topic)?;
+    }
+
+    if topics.len() > MAX_TOPICS {
+        return Err(anyhow!(
+            "Too many topics ({}). GitHub allows at most {}.",
+            topics.len(),
+            MAX_TOPICS
+        ));
+    }
+
+    Ok(())
+}
+
+/// Replace all of a repository's topics, returning the topics GitHub kept
+pub fn replace_topics(
+    client: &Client,
+    token: &str,
+    api_version: &str,
+    full_name: &str,
+    topics: &[String],
+) -> Result<Vec<String>> {
+    #[derive(Deserialize)]
+    struct TopicsResponse {
+        names: Vec<String>,
+    }
+
+    let url = format!("{}/repos/{}/topics", api_base(), full_name);
+    let response = with_headers(client.put(&url), token, api_version)
+        .json(&json!({ "names": topics }))
+        .send_logged()
+        .context("Failed to send request to GitHub API")?;
+
+    if !response.status().is_success() {
+        return Err(api_error(response));
+    }
+
+    let topics: TopicsResponse = response
+        .json()
+        .context("Failed to parse GitHub API response")?;
+
+    Ok(topics.names)
+}
+
+/// Validate a GitHub token by fetching the user it belongs to
+///
+/// Also reads the scopes GitHub granted the token from `X-OAuth-Scopes`.
+pub fn validate_github_token(client: &Client, token: &str, api_version: &str) -> Result<TokenInfo> {
+    let response = with_headers(
+        client.get(format!("{}/user", api_base())),
+        token,
+        api_version,
+    )
+    .send_logged()
+    .context("Failed to validate token with GitHub API")?;
+    check_rate_limit(&response)?;
+
+    if !response.status().is_success() {
+        return Err(api_error(response));
+    }
+
+    let scopes = response
+        .headers()
+        .get("X-OAuth-Scopes")
+        .and_then(|value| value.to_str().ok())
+        .map(|value| {
+            value
+                .split(',')
+                .map(|scope| scope.trim().to_string())
+                .filter(|scope| !scope.is_empty())
+                .collect()
+        });
+
+    let user: GitHubUser = response
+        .json()
+        .context("Failed to parse GitHub user response")?;
+
+    Ok(TokenInfo { user, scopes })
+}
+
+/// Create an issue label, returning `false` if one with that name already exists
+pub fn create_label(
+    client: &Client,
+    token: &str,
+    api_version: &str,
+    full_name: &str,
+    label: &Label,
+) -> Result<bool> {
+    let url = format!("{}/repos/{}/labels", api_base(), full_name);
+    let response = with_headers(client.post(&url), token, api_version)
+        .json(&json!({
+            "name": label.name,
+            "color": label.color.trim_start_matches('#'),
+            "description": label.description,
+        }))
+        .send_logged()
+        .context("Failed to send request to GitHub API")?;
+
+    // GitHub answers a duplicate name with 422 "already_exists"; other 422s are real errors
+    if response.status().is_success() {
+        return Ok(true);
+    }
+
+    let (status, body) = read_error_body(response);
+    if status == StatusCode::UNPROCESSABLE_ENTITY
+        && body
+            .as_deref()
+            .is_some_and(|body| body.contains("already_exists"))
+    {
+        return Ok(false);
+    }
+
+    Err(api_error_from(status, body))
+}
+
+/// Check that a homepage is an absolute http(s) URL
+pub fn validate_homepage(homepage: &str) -> Result<()> {
+    let url = reqwest::Url::parse(homepage)
+        .map_err(|_| anyhow!("Invalid homepage URL '{}'", homepage))?;
+
+    if url.scheme() != "http" && url.scheme() != "https" {
+        return Err(anyhow!(
+            "Invalid homepage URL '{}'. It must start with http:// or https://",
+            homepage
+        ));
+    }
+
+    Ok(())
+}
+
+/// Send a request, pausing and retrying while GitHub reports a secondary rate limit
+///
+/// `build` is called for every attempt since a request can only be sent once.
+/// Waits for `Retry-After` when present, otherwise backs off exponentially
+/// from `policy.delay`, and gives up after `policy.max_retries` retries. No
+/// single pause is longer than ten minutes.
+pub fn send_with_pacing(
+    policy: RetryPolicy,
+    build: impl Fn() -> RequestBuilder,
+) -> Result<Response> {
+    let mut retries = 0;
+    let mut waited = Duration::ZERO;
+    let mut backoff = policy.delay;
+
+    loop {
+        let response = build()
+            .send_logged()
+            .context("Failed to send request to GitHub API")?;
+        let status = response.status();
+
+        if status != StatusCode::FORBIDDEN && status != StatusCode::TOO_MANY_REQUESTS {
+            return Ok(response);
+        }
+
+        // The primary limit lasts until its reset, so waiting it out here isn't worthwhile
+        if let Some(limit) = RateLimit::from_headers(response.headers())
+            && limit.remaining == 0
+        {
+            return Err(limit.exceeded_error());
+        }
+
+        let retry_after = response
+            .headers()
+            .get(RETRY_AFTER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse::<u64>().ok())
+            .map(Duration::from_secs);
+        let (status, error_text) = read_error_body(response);
+
+        let secondary_limit = retry_after.is_some()
+            || error_text
+                .as_deref()
+                .is_some_and(|t| t.to_lowercase().contains("secondary rate limit"));
+        if !secondary_limit {
+            return Err(api_error_from(status, error_text));
+        }
+
+        if retries >= policy.max_retries {
+            return Err(anyhow!(
+                "GitHub secondary rate limit still in effect after {} retries ({} paused); try again later or raise --max-retries",
+                retries,
+                format_duration(waited)
+            ));
+        }
+
+        let pause = retry_after.unwrap_or(backoff).min(MAX_RETRY_PAUSE);
+        outln!(
+            "⏸️  Hit GitHub's secondary rate limit, pausing {} before retrying ({}/{})...",
+            format_duration(pause),
+            retries + 1,
+            policy.max_retries
+        );
+        thread::sleep(pause);
+        waited += pause;
+        retries += 1;
+        backoff = (backoff * 2).min(MAX_RETRY_PAUSE);
+    }
+}
+
+/// List repositories for the authenticated user, or for an organization
+///
+/// Follows the `Link` header until every page has been fetched.
+pub fn list_repos(
+    client: &Client,
+    token: &str,
+    api_version: &str,
+    org: Option<&str>,
+) -> Result<Vec<RepoSummary>> {
+    let mut url = match org {
+        Some(org) => format!("{}/orgs/{}/repos?per_page=100&type=all", api_base(), org),
+        None => format!("{}/user/repos?per_page=100&affiliation=owner", api_base()),
+    };
+    let mut repos = Vec::new();
+
+    loop {
+        let response = with_headers(client.get(&url), token, api_version)
+            .send_logged()
+            .context("Failed to send request to GitHub API")?;
+
+        if !response.status().is_success() {
+            return Err(api_error(response));
+        }
+
+        let next = next_page_url(response.headers());
+
+        let page: Vec<RepoSummary> = response
+            .json()
+            .context("Failed to parse GitHub API response")?;
+        repos.extend(page);
+
+        match next {
+            Some(next) => url = next,
+            None => return Ok(repos),
+        }
+    }
+}
+
+/// Look up a single repository, returning `None` if it doesn't exist
+///
+/// GitHub resolves repository names case-insensitively, so the returned
+/// summary carries the repository's actual casing.
+pub fn find_repo(
+    client: &Client,
+    token: &str,
+    api_version: &str,
+    owner: &str,
+    name: &str,
+) -> Result<Option<RepoSummary>> {
+    get_repo(client, token, api_version, owner, name)
+}
+
+/// Fetch a single repository as `T`, returning `None` if it doesn't exist
+pub fn get_repo<T: DeserializeOwned>(
+    client: &Client,
+    token: &str,
+    api_version: &str,
+    owner: &str,
+    name: &str,
+) -> Result<Option<T>> {
+    let url = format!("{}/repos/{}/{}", api_base(), owner, name);
+    let response = with_headers(client.get(&url), token, api_version)
+        .send_logged()
+        .context("Failed to send request to GitHub API")?;
+
+    if response.status() == StatusCode::NOT_FOUND {
+        return Ok(None);
+    }
+
+    if !response.status().is_success() {
+        return Err(api_error(response));
+    }
+
+    let repo = response
+        .json()
+        .context("Failed to parse GitHub API response")?;
+
+    Ok(Some(repo))
+}
+
+/// Whether `owner/name` has a branch called `branch`
+pub fn branch_exists(
+    client: &Client,
+    token: &str,
+    api_version: &str,
+    owner: &str,
+    name: &str,
+    branch: &str,
+) -> Result<bool> {
+    let url = format!(
+        "{}/repos/{}/{}/branches/{}",
+        api_base(),
+        owner,
+        name,
+        branch
+    );
+    let response = with_headers(client.get(&url), token, api_version)
+        .send_logged()
+        .context("Failed to send request to GitHub API")?;
+
+    if response.status() == StatusCode::NOT_FOUND {
+        return Ok(false);
+    }
+
+    if !response.status().is_success() {
+        return Err(api_error(response));
+    }
+
+    Ok(true)
+}
+
+/// A `.gitignore` template from GitHub's template collection
+#[derive(Debug, Deserialize)]
+struct GitignoreTemplate {
+    source: String,
+}
+
+/// Fetch the contents of a `.gitignore` template by name
+///
+/// The endpoint is public, so a token is only used to get the higher rate limit.
+pub fn fetch_gitignore_template(
+    client: &Client,
+    token: Option<&str>,
+    api_version: &str,
+    name: &str,
+) -> Result<String> {
+    let url = format!("{}/gitignore/templates/{}", api_base(), name);
+    let response = public_get(client, token, api_version, &url)
+        .send_logged()
+        .context("Failed to send request to GitHub API")?;
+
+    if response.status() == StatusCode::NOT_FOUND {
+        return Err(anyhow!("No .gitignore template named '{}'", name));
+    }
+
+    if !response.status().is_success() {
+        return Err(api_error(response));
+    }
+
+    let template: GitignoreTemplate = response
+        .json()
+        .context("Failed to parse GitHub API response")?;
+
+    Ok(template.source)
+}
+
+/// List the keys of every license GitHub can add to a new repository
+pub fn list_license_keys(
+    client: &Client,
+    token: Option<&str>,
+    api_version: &str,
+) -> Result<Vec<String>> {
+    #[derive(Deserialize)]
+    struct License {
+        key: String,
+    }
+
+    let url = format!("{}/licenses", api_base());
+    let response = public_get(client, token, api_version, &url)
+        .send_logged()
+        .context("Failed to send request to GitHub API")?;
+
+    if !response.status().is_success() {
+        return Err(api_error(response));
+    }
+
+    let licenses: Vec<License> = response
+        .json()
+        .context("Failed to parse GitHub API response")?;
+
+    Ok(licenses.into_iter().map(|license| license.key).collect())
+}
+
+/// List the names of every `.gitignore` template GitHub offers
+pub fn list_gitignore_templates(
+    client: &Client,
+    token: Option<&str>,
+    api_version: &str,
+) -> Result<Vec<String>> {
+    let url = format!("{}/gitignore/templates", api_base());
+    let response = public_get(client, token, api_version, &url)
+        .send_logged()
+        .context("Failed to send request to GitHub API")?;
+
+    if !response.status().is_success() {
+        return Err(api_error(response));
+    }
+
+    response
+        .json()
+        .context("Failed to parse GitHub API response")
+}
+
+/// A gist as far as repogen reads it
+#[derive(Debug, Deserialize)]
+struct Gist {
+    files: BTreeMap<String, GistFile>,
+}
+
+/// One file in a gist
+#[derive(Debug, Deserialize)]
+struct GistFile {
+    content: Option<String>,
+}
+
+/// Fetch a file from a gist by ID, returning its name and content
+///
+/// Takes the file called `name` when the gist has one, otherwise its only
+/// file.
+pub fn fetch_gist_file(
+    client: &Client,
+    token: Option<&str>,
+    api_version: &str,
+    id: &str,
+    name: &str,
+) -> Result<(String, String)> {
+    let url = format!("{}/gists/{}", api_base(), id);
+    let response = public_get(client, token, api_version, &url)
+        .send_logged()
+        .context("Failed to send request to GitHub API")?;
+
+    if response.status() == StatusCode::NOT_FOUND {
+        return Err(anyhow!("No gist with ID '{}'", id));
+    }
+
+    if !response.status().is_success() {
+        return Err(api_error(response));
+    }
+
+    let gist: Gist = response
+        .json()
+        .context("Failed to parse GitHub API response")?;
+
+    let (file_name, file) = match gist.files.get_key_value(name) {
+        Some(entry) => entry,
+        None if gist.files.len() == 1 => gist.files.iter().next().unwrap(),
+        None => {
+            return Err(anyhow!(
+                "Gist '{}' has no {} and several files ({}); use the raw URL of the one you want",
+                id,
+                name,
+                gist.files.keys().cloned().collect::<Vec<_>>().join(", ")
+            ));
+        }
+    };
+
+    let content = file
+        .content
+        .clone()
+        .ok_or_else(|| anyhow!("Gist '{}' has no content in {}", id, file_name))?;
+    Ok((file_name.clone(), content))
+}
+
+/// GET a public endpoint, authenticating only when a token is available
+fn public_get(
+    client: &Client,
+    token: Option<&str>,
+    api_version: &str,
+    url: &str,
+) -> RequestBuilder {
+    match token {
+        Some(token) => with_headers(client.get(url), token, api_version),
+        None => client
+            .get(url)
+            .header("Accept", "application/vnd.github+json")
+            .header("X-GitHub-Api-Version", api_version),
+    }
+}
+
+/// The part of a repository file's metadata repogen needs
+#[derive(Debug, Deserialize)]
+struct FileContents {
+    sha: String,
+}
+
+/// Blob SHA of a file on the default branch, or `None` if it doesn't exist
+pub fn file_sha(
+    client: &Client,
+    token: &str,
+    api_version: &str,
+    full_name: &str,
+    path: &str,
+) -> Result<Option<String>> {
+    let url = format!("{}/repos/{}/contents/{}", api_base(), full_name, path);
+    let response = with_headers(client.get(&url), token, api_version)
+        .send_logged()
+        .context("Failed to send request to GitHub API")?;
+
+    if response.status() == StatusCode::NOT_FOUND {
+        return Ok(None);
+    }
+
+    if !response.status().is_success() {
+        return Err(api_error(response));
+    }
+
+    let file: FileContents = response
+        .json()
+        .context("Failed to parse GitHub API response")?;
+
+    Ok(Some(file.sha))
+}
+
+/// Create or update a file in a repository with a single commit on the default branch
+///
+/// Updating an existing file needs its current blob `sha`, otherwise GitHub
+/// rejects the commit as a conflict.
+#[allow(clippy::too_many_arguments)]
+pub fn put_file(
+    client: &Client,
+    token: &str,
+    api_version: &str,
+    full_name: &str,
+    path: &str,
+    content: &str,
+    message: &str,
+    sha: Option<&str>,
+) -> Result<()> {
+    let url = format!("{}/repos/{}/contents/{}", api_base(), full_name, path);
+    let mut body = json!({
+        "message": message,
+        "content": BASE64_STANDARD.encode(content),
+    });
+    if let Some(sha) = sha {
+        body["sha"] = json!(sha);
+    }
+    let response = with_headers(client.put(&url), token, api_version)
+        .json(&body)
+        .send_logged()
+        .context("Failed to send request to GitHub API")?;
+
+    if !response.status().is_success() {
+        return Err(api_error(response));
+    }
+
+    Ok(())
+}
+
+/// Extract the `rel="next"` URL from a `Link` pagination header
+fn next_page_url(headers: &HeaderMap) -> Option<String> {
+    let link = headers.get(LINK)?.to_str().ok()?;
+
+    link.split(',').find_map(|part| {
+        let (url, params) = part.split_once(';')?;
+        if params.split(';').any(|p| p.trim() == "rel=\"next\"") {
+            Some(
+                url.trim()
+                    .trim_start_matches('<')
+                    .trim_end_matches('>')
+                    .to_string(),
+            )
+        } else {
+            None
+        }
+    })
+}
@@ -0,0 +1,226 @@
+use crate::utils::config::GITIGNORE_OPTIONS;
+use crate::utils::http::SendLogged;
+use crate::utils::{cache, github, suggest};
+use anyhow::{Context, Result, anyhow};
+use reqwest::StatusCode;
+use reqwest::blocking::Client;
+use reqwest::header::CONTENT_TYPE;
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+/// Where GitHub's template list is cached, under the config directory
+const CACHE_FILE: &str = "gitignore_cache.json";
+
+/// Files that mark a project's language, with the GitHub template they map to
+const LANGUAGE_INDICATORS: &[(&str, &str)] = &[
+    ("Cargo.toml", "Rust"),
+    ("package.json", "Node"),
+    ("requirements.txt", "Python"),
+    ("pyproject.toml", "Python"),
+    ("setup.py", "Python"),
+    ("Pipfile", "Python"),
+    ("go.mod", "Go"),
+    ("pom.xml", "Maven"),
+    ("build.gradle", "Gradle"),
+    ("build.gradle.kts", "Gradle"),
+    ("Gemfile", "Ruby"),
+    ("composer.json", "Composer"),
+    ("CMakeLists.txt", "CMake"),
+    ("pubspec.yaml", "Dart"),
+    ("Package.swift", "Swift"),
+    ("mix.exs", "Elixir"),
+    ("stack.yaml", "Haskell"),
+];
+
+/// File extensions that mark a project's language
+const EXTENSION_INDICATORS: &[(&str, &str)] = &[
+    ("csproj", "VisualStudio"),
+    ("sln", "VisualStudio"),
+    ("cabal", "Haskell"),
+];
+
+/// Guess `.gitignore` templates from the files at the top of `dir`
+///
+/// Returns template names in a stable order without duplicates.
+pub fn detect_templates(dir: &Path) -> Vec<&'static str> {
+    let mut templates = Vec::new();
+
+    for (file, template) in LANGUAGE_INDICATORS {
+        if dir.join(file).is_file() && !templates.contains(template) {
+            templates.push(*template);
+        }
+    }
+
+    let extensions: Vec<String> = fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok())
+                .filter_map(|entry| {
+                    entry
+                        .path()
+                        .extension()
+                        .map(|ext| ext.to_string_lossy().into_owned())
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    for (extension, template) in EXTENSION_INDICATORS {
+        if extensions.iter().any(|ext| ext == extension) && !templates.contains(template) {
+            templates.push(*template);
+        }
+    }
+
+    templates
+}
+
+/// Join several templates into one `.gitignore`, each under a header
+///
+/// Patterns an earlier section already lists are dropped. Comments and blank
+/// lines are kept, so each section still reads like its template.
+pub fn combine(sections: &[(String, String)]) -> String {
+    let mut seen = HashSet::new();
+    sections
+        .iter()
+        .map(|(name, content)| {
+            let lines: Vec<&str> = content
+                .trim_end()
+                .lines()
+                .filter(|line| {
+                    let pattern = line.trim();
+                    pattern.is_empty() || pattern.starts_with('#') || seen.insert(pattern)
+                })
+                .collect();
+            format!("### {} ###\n{}\n", name, lines.join("\n"))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Template names in a `--gitignore` value, which may list several
+/// separated by commas, e.g. `Rust,Node`
+pub fn split_templates(value: &str) -> Vec<String> {
+    let mut templates: Vec<String> = Vec::new();
+    for name in value
+        .split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+    {
+        if !templates.iter().any(|seen| seen.eq_ignore_ascii_case(name)) {
+            templates.push(name.to_string());
+        }
+    }
+    templates
+}
+
+/// Every template GitHub offers, cached for a week
+pub fn available_templates(
+    client: &Client,
+    token: Option<&str>,
+    api_version: &str,
+) -> Result<Vec<String>> {
+    cache::weekly(CACHE_FILE, || {
+        github::list_gitignore_templates(client, token, api_version)
+    })
+}
+
+/// Template names known without going online: the cache if there is one,
+/// otherwise the built-in list
+pub fn known_templates() -> Vec<String> {
+    cache::read(CACHE_FILE).unwrap_or_else(|| {
+        GITIGNORE_OPTIONS[1..]
+            .iter()
+            .map(|name| name.to_string())
+            .collect()
+    })
+}
+
+/// Check a template name against GitHub's list, suggesting the closest match
+pub fn validate_template(templates: &[String], name: &str) -> Result<()> {
+    if templates.iter().any(|template| template == name) {
+        return Ok(());
+    }
+
+    match suggest::closest(templates, name) {
+        Some(template) => Err(anyhow!(
+            "Unknown .gitignore template '{}'. Did you mean '{}'?",
+            name,
+            template
+        )),
+        None => Err(anyhow!(
+            "Unknown .gitignore template '{}'. Run `repogen config --edit` to browse the available templates.",
+            name
+        )),
+    }
+}
+
+/// Fetch a `.gitignore` from a raw URL or gist ID, returning a label and its content
+///
+/// Gist page URLs are read through the API like bare IDs. Anything that
+/// isn't plain text (e.g. a web page rather than the raw file) is refused.
+pub fn fetch_custom(
+    client: &Client,
+    token: Option<&str>,
+    api_version: &str,
+    source: &str,
+) -> Result<(String, String)> {
+    if let Some(id) = gist_id(source) {
+        let (_, content) = github::fetch_gist_file(client, token, api_version, id, ".gitignore")?;
+        return Ok((format!("gist {}", id), content));
+    }
+
+    if !source.starts_with("https://") && !source.starts_with("http://") {
+        return Err(anyhow!(
+            "'{}' is neither a URL nor a gist ID; pass the raw URL of the .gitignore",
+            source
+        ));
+    }
+
+    let response = client
+        .get(source)
+        .send_logged()
+        .with_context(|| format!("Failed to fetch {}", source))?;
+
+    if response.status() == StatusCode::NOT_FOUND {
+        return Err(anyhow!("No .gitignore found at {} (404 Not Found)", source));
+    }
+
+    if !response.status().is_success() {
+        return Err(anyhow!("Failed to fetch {}: {}", source, response.status()));
+    }
+
+    let content_type = response
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or("text/plain")
+        .to_string();
+    if !content_type.starts_with("text/plain") {
+        return Err(anyhow!(
+            "{} returned {} instead of plain text; use the file's raw URL",
+            source,
+            content_type
+        ));
+    }
+
+    let content = response
+        .text()
+        .with_context(|| format!("Failed to read {}", source))?;
+    if content.trim().is_empty() {
+        return Err(anyhow!("{} is empty", source));
+    }
+
+    Ok((source.to_string(), content))
+}
+
+/// The gist ID in a bare ID or a gist page URL
+fn gist_id(source: &str) -> Option<&str> {
+    let id = match source.strip_prefix("https://gist.github.com/") {
+        Some(path) if !path.contains("/raw") => path.trim_end_matches('/').rsplit('/').next()?,
+        Some(_) => return None,
+        None => source,
+    };
+
+    (!id.is_empty() && id.chars().all(|c| c.is_ascii_hexdigit())).then_some(id)
+}
@@ -0,0 +1,38 @@
+use crate::utils::output;
+use anyhow::{Context, Result, anyhow};
+use std::io;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+/// Run a user-configured shell command in `dir`, streaming its output
+///
+/// Goes through `sh -c` (`cmd /C` on Windows) so pipes and `&&` work. When
+/// stdout is reserved for scripts (`--quiet`, `--json`), the command's output
+/// goes to stderr instead.
+pub fn run(command: &str, dir: &Path) -> Result<()> {
+    let mut shell = if cfg!(windows) {
+        let mut shell = Command::new("cmd");
+        shell.arg("/C");
+        shell
+    } else {
+        let mut shell = Command::new("sh");
+        shell.arg("-c");
+        shell
+    };
+
+    if output::is_quiet() {
+        shell.stdout(Stdio::from(io::stderr()));
+    }
+
+    let status = shell
+        .arg(command)
+        .current_dir(dir)
+        .status()
+        .with_context(|| format!("Failed to run '{}'", command))?;
+
+    if !status.success() {
+        return Err(anyhow!("'{}' exited with {}", command, status));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,152 @@
+use crate::cli;
+use crate::errln;
+use crate::utils::config::{Config, DEFAULT_REQUEST_TIMEOUT_SECS};
+use anyhow::{Context, Result, anyhow};
+use reqwest::Certificate;
+use reqwest::blocking::{Client, RequestBuilder, Response};
+use reqwest::header::{AUTHORIZATION, HeaderName, HeaderValue};
+use std::fs;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+/// `User-Agent` sent with every request, which GitHub requires
+pub const USER_AGENT: &str = "repogen-cli";
+
+/// Settings applied to every HTTP client repogen builds
+#[derive(Clone, Default)]
+pub struct ClientOptions {
+    /// PEM file with extra CA certificates to trust (e.g. an internal CA)
+    pub ca_bundle: Option<PathBuf>,
+    /// Skip TLS certificate verification entirely
+    pub insecure: bool,
+    /// Give up on a request after this long (default: 30 seconds)
+    pub timeout: Option<Duration>,
+}
+
+impl ClientOptions {
+    /// Combine global flags with config defaults
+    ///
+    /// Warns loudly when certificate verification is turned off.
+    pub fn resolve(global: &cli::GlobalArgs, config: &Config) -> Self {
+        let ca_bundle = global
+            .ca_bundle
+            .clone()
+            .or_else(|| config.ca_bundle.as_deref().map(Config::expand_home));
+
+        if global.insecure {
+            errln!(
+                "⚠️  TLS certificate verification is DISABLED (--insecure). \
+                 Anyone on the network can read or change your GitHub traffic, including your token."
+            );
+        }
+
+        Self {
+            ca_bundle,
+            insecure: global.insecure,
+            timeout: Some(config.request_timeout()),
+        }
+    }
+}
+
+/// Build a blocking client with the configured TLS settings and timeout
+pub fn client(options: &ClientOptions) -> Result<Client> {
+    let timeout = options
+        .timeout
+        .unwrap_or(Duration::from_secs(DEFAULT_REQUEST_TIMEOUT_SECS));
+    let mut builder = Client::builder().user_agent(USER_AGENT).timeout(timeout);
+
+    if let Some(path) = &options.ca_bundle {
+        let pem = fs::read(path)
+            .with_context(|| format!("Failed to read CA bundle: {}", path.display()))?;
+        let certificates = Certificate::from_pem_bundle(&pem)
+            .with_context(|| format!("Failed to parse CA bundle: {}", path.display()))?;
+
+        if certificates.is_empty() {
+            return Err(anyhow!(
+                "CA bundle contains no certificates: {}",
+                path.display()
+            ));
+        }
+
+        for certificate in certificates {
+            builder = builder.add_root_certificate(certificate);
+        }
+    }
+
+    if options.insecure {
+        builder = builder.danger_accept_invalid_certs(true);
+    }
+
+    builder.build().context("Failed to create HTTP client")
+}
+
+/// Replace a timed-out request's error with a clear explanation
+///
+/// Errors are shown by their outermost context, which would otherwise hide
+/// that the request hung rather than failed.
+pub fn explain_timeout(error: anyhow::Error) -> anyhow::Error {
+    let timed_out = error.chain().any(|cause| {
+        cause
+            .downcast_ref::<reqwest::Error>()
+            .is_some_and(reqwest::Error::is_timeout)
+    });
+
+    if timed_out {
+        error.context(
+            "Request timed out. Check your connection, or raise request_timeout_secs if it is slow.",
+        )
+    } else {
+        error
+    }
+}
+
+/// Sending that reports each request under `--verbose`
+///
+/// `-v` logs the method, URL, status and timing, `-vvv` adds the request
+/// headers with credentials redacted.
+pub trait SendLogged {
+    /// Send the request like `send`, logging it first
+    fn send_logged(self) -> reqwest::Result<Response>;
+}
+
+impl SendLogged for RequestBuilder {
+    fn send_logged(self) -> reqwest::Result<Response> {
+        if !log::log_enabled!(log::Level::Info) {
+            return self.send();
+        }
+
+        let (client, request) = self.build_split();
+        let request = request?;
+        let method = request.method().clone();
+        let url = request.url().clone();
+
+        log::info!("{} {}", method, url);
+        for (name, value) in request.headers() {
+            log::trace!("  {}: {}", name, redact(name, value));
+        }
+
+        let started = Instant::now();
+        let result = client.execute(request);
+        let elapsed = started.elapsed().as_millis();
+        match &result {
+            Ok(response) => log::info!(
+                "{} {} -> {} ({} ms)",
+                method,
+                url,
+                response.status(),
+                elapsed
+            ),
+            Err(e) => log::info!("{} {} failed after {} ms: {}", method, url, elapsed, e),
+        }
+
+        result
+    }
+}
+
+/// A header value fit for the log, with credentials hidden
+fn redact(name: &HeaderName, value: &HeaderValue) -> String {
+    if name == AUTHORIZATION {
+        return "[redacted]".to_string();
+    }
+    value.to_str().unwrap_or("[binary]").to_string()
+}
@@ -0,0 +1,70 @@
+use console::Term;
+use std::process;
+use std::sync::Once;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+static INSTALL: Once = Once::new();
+static WATCHING: AtomicBool = AtomicBool::new(false);
+static INTERRUPTED: AtomicBool = AtomicBool::new(false);
+
+/// How often a watched sleep checks for Ctrl-C
+const CHECK_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Turns Ctrl-C into a flag for as long as it's alive
+///
+/// Long waits check the flag and stop cleanly instead of dying mid-line.
+/// Outside a watch, Ctrl-C exits as usual, after restoring the cursor.
+pub struct Watch;
+
+impl Watch {
+    /// Start catching Ctrl-C, installing the handler on first use
+    pub fn start() -> Self {
+        INSTALL.call_once(|| {
+            let installed = ctrlc::set_handler(|| {
+                if WATCHING.load(Ordering::SeqCst) {
+                    INTERRUPTED.store(true, Ordering::SeqCst);
+                } else {
+                    let _ = Term::stderr().show_cursor();
+                    process::exit(130);
+                }
+            });
+            if let Err(e) = installed {
+                log::debug!("Could not install the Ctrl-C handler: {}", e);
+            }
+        });
+
+        INTERRUPTED.store(false, Ordering::SeqCst);
+        WATCHING.store(true, Ordering::SeqCst);
+        Watch
+    }
+
+    /// Whether Ctrl-C was pressed since the watch started
+    pub fn interrupted(&self) -> bool {
+        INTERRUPTED.load(Ordering::SeqCst)
+    }
+
+    /// Sleep for `duration`, waking early on Ctrl-C
+    ///
+    /// Returns whether Ctrl-C was pressed.
+    pub fn sleep(&self, duration: Duration) -> bool {
+        let deadline = Instant::now() + duration;
+
+        while !self.interrupted() {
+            let left = deadline.saturating_duration_since(Instant::now());
+            if left.is_zero() {
+                break;
+            }
+            thread::sleep(left.min(CHECK_INTERVAL));
+        }
+
+        self.interrupted()
+    }
+}
+
+impl Drop for Watch {
+    fn drop(&mut self) {
+        WATCHING.store(false, Ordering::SeqCst);
+    }
+}
@@ -0,0 +1,44 @@
+use crate::utils::config::Config;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+/// File under the config directory holding the last repository `new` created
+const FILE: &str = "last_repo.json";
+
+/// The repository most recently created by `repogen new`
+#[derive(Serialize, Deserialize)]
+pub struct LastRepo {
+    /// `owner/name`
+    pub full_name: String,
+    pub html_url: String,
+    /// As reported by GitHub, e.g. `2026-10-16T10:00:00Z`
+    #[serde(default)]
+    pub created_at: String,
+}
+
+/// Remember `repo` as the last created repository
+pub fn save(repo: &LastRepo) -> Result<()> {
+    fs::create_dir_all(Config::config_dir()?)?;
+    fs::write(path()?, serde_json::to_string_pretty(repo)?)?;
+    Ok(())
+}
+
+/// The last created repository, if one was recorded
+pub fn load() -> Result<Option<LastRepo>> {
+    let path = path()?;
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let content =
+        fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))?;
+    let repo = serde_json::from_str(&content)
+        .with_context(|| format!("Failed to parse {}", path.display()))?;
+    Ok(Some(repo))
+}
+
+fn path() -> Result<PathBuf> {
+    Ok(Config::config_dir()?.join(FILE))
+}
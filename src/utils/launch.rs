@@ -1,6 +1,13 @@
+use crate::utils::symbols::{self, SymbolSet};
 use console::Style;
 
 pub fn display_title() {
+    // The block-letter banner needs Unicode, so keep it plain on ASCII terminals
+    if symbols::active() == SymbolSet::Ascii {
+        println!("\n    repogen - Cloud-first repository creation for developers\n");
+        return;
+    }
+
     let title_style = Style::new().bold().cyan();
     let subtitle_style = Style::new().dim();
 
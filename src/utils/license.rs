@@ -0,0 +1,37 @@
+use crate::utils::{cache, github, suggest};
+use anyhow::{Result, anyhow};
+use reqwest::blocking::Client;
+
+/// Where GitHub's license keys are cached, under the config directory
+const CACHE_FILE: &str = "license_cache.json";
+
+/// Every license key GitHub offers, cached for a week
+pub fn available_keys(
+    client: &Client,
+    token: Option<&str>,
+    api_version: &str,
+) -> Result<Vec<String>> {
+    cache::weekly(CACHE_FILE, || {
+        github::list_license_keys(client, token, api_version)
+    })
+}
+
+/// Check a license against GitHub's keys, ignoring case, suggesting the closest match
+pub fn validate(keys: &[String], license: &str) -> Result<()> {
+    if keys.iter().any(|key| key.eq_ignore_ascii_case(license)) {
+        return Ok(());
+    }
+
+    match suggest::closest(keys, license) {
+        Some(key) => Err(anyhow!(
+            "Unknown license '{}'. Did you mean '{}'?",
+            license,
+            key
+        )),
+        None => Err(anyhow!(
+            "Unknown license '{}'. Valid keys: {}",
+            license,
+            keys.join(", ")
+        )),
+    }
+}
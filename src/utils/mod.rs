@@ -1,5 +1,26 @@
+pub mod browser;
+pub mod cache;
 pub mod config;
+pub mod editor;
+pub mod git;
+pub mod gitattributes;
+pub mod github;
+pub mod gitignore;
+pub mod hook;
+pub mod http;
+pub mod interrupt;
+pub mod last_repo;
 pub mod launch;
+pub mod license;
+pub mod output;
+pub mod preset;
+pub mod preview;
+pub mod prompt;
+pub mod readme;
+pub mod secrets;
+pub mod suggest;
+pub mod symbols;
+pub mod timing;
 
 // Re-export for convenience
 // pub use config::Config;
@@ -0,0 +1,80 @@
+use crate::outln;
+use console::style;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static QUIET: AtomicBool = AtomicBool::new(false);
+
+/// Silence `outln!` for the rest of the run
+///
+/// For commands whose stdout is meant for scripts. `errln!` still prints.
+pub fn set_quiet() {
+    QUIET.store(true, Ordering::Relaxed);
+}
+
+/// Whether `outln!` output is silenced
+pub fn is_quiet() -> bool {
+    QUIET.load(Ordering::Relaxed)
+}
+
+/// How a command prints its results
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Aligned, colored output for people
+    Human,
+    /// Stable tab-separated records for scripts
+    Porcelain,
+}
+
+impl OutputFormat {
+    /// Pick the format from a command's `--porcelain` flag
+    pub fn from_porcelain(porcelain: bool) -> Self {
+        if porcelain {
+            Self::Porcelain
+        } else {
+            Self::Human
+        }
+    }
+}
+
+/// Print one porcelain record: fields joined by tabs, one record per line
+///
+/// Tabs and newlines inside a field are replaced with spaces so every record
+/// stays on one line with a fixed number of fields. Empty fields are printed
+/// as `-`. Goes through `println!` directly since porcelain output never
+/// carries decorative symbols.
+pub fn print_porcelain(fields: &[&str]) {
+    let fields: Vec<String> = fields
+        .iter()
+        .map(|field| {
+            if field.is_empty() {
+                "-".to_string()
+            } else {
+                field.replace(['\t', '\n', '\r'], " ")
+            }
+        })
+        .collect();
+
+    println!("{}", fields.join("\t"));
+}
+
+/// A sample invocation shown by a command's `--examples` flag
+pub struct Example {
+    /// What the invocation does
+    pub description: &'static str,
+    /// The full command line, starting with `repogen`
+    pub command: &'static str,
+}
+
+/// Print a command's usage examples
+pub fn print_examples(command: &str, examples: &[Example]) {
+    outln!(
+        "{}",
+        style(format!("Examples for repogen {}:", command)).bold()
+    );
+
+    for example in examples {
+        outln!();
+        outln!("  {}", style(format!("# {}", example.description)).dim());
+        outln!("  $ {}", example.command);
+    }
+}
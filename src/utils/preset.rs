@@ -0,0 +1,196 @@
+use crate::cli;
+use crate::utils::config::{self, Config, Label};
+use anyhow::{Context, Result, anyhow};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+/// A saved set of `repogen new` flags, applied with `--template-preset`
+///
+/// Only flags left off the command line are filled in from the preset.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Preset {
+    /// Description; `{name}` is replaced with the repository name
+    pub description: Option<String>,
+    pub private: Option<bool>,
+    pub license: Option<String>,
+    pub gitignore: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub topics: Vec<String>,
+    pub readme_template: Option<String>,
+    pub default_branch: Option<String>,
+    #[serde(default)]
+    pub protect: bool,
+    pub post_clone: Option<String>,
+    /// Labels created instead of the config's default_labels
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub labels: Vec<Label>,
+}
+
+impl Preset {
+    /// Build a preset from `template save` flags
+    pub fn from_args(args: &cli::TemplateSave) -> Result<Self> {
+        let labels = args
+            .label
+            .iter()
+            .map(|label| parse_label(label))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            description: args.description.clone(),
+            private: config::requested_privacy(Some(args.private), Some(args.public)),
+            license: args.license.clone(),
+            gitignore: args.gitignore.clone(),
+            topics: args.topic.clone(),
+            readme_template: args.readme_template.clone(),
+            default_branch: args.default_branch.clone(),
+            protect: args.protect,
+            post_clone: args.post_clone.clone(),
+            labels,
+        })
+    }
+
+    /// Fill in the flags `args` doesn't already set, for a repository called `name`
+    pub fn apply(&self, args: &mut cli::New, name: &str) {
+        if args.description.is_none() && args.lang.is_none() {
+            args.description = self
+                .description
+                .as_ref()
+                .map(|description| description.replace("{name}", name));
+        }
+        if args.visibility.is_none() && args.private.is_none() && args.public.is_none() {
+            args.visibility = self
+                .private
+                .map(|private| if private { "private" } else { "public" }.to_string());
+        }
+        fill(&mut args.license, &self.license);
+        fill(&mut args.gitignore, &self.gitignore);
+        if args.topic.is_empty() {
+            args.topic = self.topics.clone();
+        }
+        fill(&mut args.readme_template, &self.readme_template);
+        fill(&mut args.default_branch, &self.default_branch);
+        args.protect |= self.protect;
+        fill(&mut args.post_clone, &self.post_clone);
+    }
+}
+
+/// Set `flag` from the preset unless it was given
+fn fill(flag: &mut Option<String>, preset: &Option<String>) {
+    if flag.is_none() {
+        flag.clone_from(preset);
+    }
+}
+
+/// Parse a `NAME:COLOR[:DESCRIPTION]` label
+fn parse_label(value: &str) -> Result<Label> {
+    let mut parts = value.splitn(3, ':');
+    let name = parts.next().unwrap_or_default().trim();
+    let color = parts.next().unwrap_or_default().trim();
+    let description = parts.next().map(str::trim).filter(|d| !d.is_empty());
+
+    if name.is_empty() || !config::is_label_color(color) {
+        return Err(anyhow!(
+            "Invalid --label '{}': expected NAME:COLOR[:DESCRIPTION], e.g. bug:d73a4a",
+            value
+        ));
+    }
+
+    Ok(Label {
+        name: name.to_string(),
+        color: color.trim_start_matches('#').to_lowercase(),
+        description: description.map(str::to_string),
+    })
+}
+
+/// Directory holding one file per preset
+pub fn presets_dir() -> Result<PathBuf> {
+    Ok(Config::config_dir()?.join("templates"))
+}
+
+/// File a preset is stored in
+pub fn preset_file(name: &str) -> Result<PathBuf> {
+    if name.is_empty()
+        || !name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_'))
+    {
+        return Err(anyhow!(
+            "Invalid preset name '{}': use letters, digits, '-' and '_'",
+            name
+        ));
+    }
+
+    Ok(presets_dir()?.join(format!("{}.toml", name)))
+}
+
+/// Names of the saved presets, sorted
+pub fn list() -> Result<Vec<String>> {
+    let entries = match fs::read_dir(presets_dir()?) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e).context("Failed to read templates directory"),
+    };
+
+    let mut names: Vec<String> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "toml"))
+        .filter_map(|path| {
+            path.file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned())
+        })
+        .collect();
+    names.sort();
+
+    Ok(names)
+}
+
+/// Read a saved preset
+pub fn load(name: &str) -> Result<Preset> {
+    let path = preset_file(name)?;
+    let content = match fs::read_to_string(&path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            let saved = list()?;
+            return Err(if saved.is_empty() {
+                anyhow!(
+                    "No preset named '{}'. Save one with `repogen template save {}`.",
+                    name,
+                    name
+                )
+            } else {
+                anyhow!(
+                    "No preset named '{}'. Saved presets: {}",
+                    name,
+                    saved.join(", ")
+                )
+            });
+        }
+        Err(e) => return Err(e).with_context(|| format!("Failed to read {}", path.display())),
+    };
+
+    toml::from_str(&content).with_context(|| format!("Failed to parse {}", path.display()))
+}
+
+/// Write a preset, returning where it was saved
+pub fn save(name: &str, preset: &Preset) -> Result<PathBuf> {
+    let path = preset_file(name)?;
+    fs::create_dir_all(presets_dir()?).context("Failed to create templates directory")?;
+    let content = toml::to_string_pretty(preset).context("Failed to serialize preset")?;
+    fs::write(&path, content).with_context(|| format!("Failed to write {}", path.display()))?;
+
+    Ok(path)
+}
+
+/// Remove a saved preset
+pub fn delete(name: &str) -> Result<()> {
+    let path = preset_file(name)?;
+    match fs::remove_file(&path) {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            Err(anyhow!("No preset named '{}'", name))
+        }
+        Err(e) => Err(e).with_context(|| format!("Failed to delete {}", path.display())),
+    }
+}
@@ -0,0 +1,36 @@
+use crate::outln;
+use console::Style;
+
+/// Print a file's content in a box, with light markdown styling
+pub fn print_file(file_name: &str, content: &str) {
+    let heading = Style::new().cyan().bold();
+    let code = Style::new().dim();
+    let border = Style::new().dim();
+
+    outln!(
+        "{}",
+        border.apply_to(format!(
+            "┌─ {} {}",
+            file_name,
+            "─".repeat(46usize.saturating_sub(file_name.chars().count()))
+        ))
+    );
+
+    let mut in_code_block = false;
+    for line in content.lines() {
+        let styled = if line.starts_with("```") {
+            in_code_block = !in_code_block;
+            code.apply_to(line).to_string()
+        } else if in_code_block {
+            code.apply_to(line).to_string()
+        } else if line.starts_with('#') {
+            heading.apply_to(line).to_string()
+        } else {
+            line.to_string()
+        };
+
+        outln!("{} {}", border.apply_to("│"), styled);
+    }
+
+    outln!("{}", border.apply_to("└".to_string() + &"─".repeat(49)));
+}
@@ -0,0 +1,137 @@
+use crate::outln;
+use console::style;
+use dialoguer::{
+    BasicHistory, Completion, Confirm, FuzzySelect, History, MultiSelect, Select,
+    theme::ColorfulTheme,
+};
+
+/// Answer a yes/no prompt receives when `--assume-yes` is set
+#[derive(Clone, Copy)]
+pub enum Assumed {
+    /// Confirmations: proceed
+    Yes,
+    /// Preference questions: keep the current value
+    Default,
+    /// Retry loops and other prompts that would repeat forever
+    No,
+}
+
+/// Ask a yes/no question, or answer it automatically under `--assume-yes`
+pub fn confirm(
+    theme: &ColorfulTheme,
+    assume_yes: bool,
+    prompt: &str,
+    default: bool,
+    assumed: Assumed,
+) -> bool {
+    if assume_yes {
+        let answer = match assumed {
+            Assumed::Yes => true,
+            Assumed::Default => default,
+            Assumed::No => false,
+        };
+
+        outln!(
+            "{} {} · {} {}",
+            style("✔").green(),
+            prompt,
+            if answer { "yes" } else { "no" },
+            style("(--assume-yes)").dim()
+        );
+        return answer;
+    }
+
+    Confirm::with_theme(theme)
+        .with_prompt(prompt)
+        .default(default)
+        .interact()
+        .unwrap()
+}
+
+/// Up-arrow history for an `Input` prompt, seeded with earlier values
+///
+/// `values` is ordered most recent first, so the first up-arrow press recalls
+/// `values[0]`.
+pub fn seeded_history(values: &[String]) -> BasicHistory {
+    let mut history = BasicHistory::new().no_duplicates(true);
+    for value in values.iter().rev() {
+        history.write(value);
+    }
+    history
+}
+
+/// Tab completion that finishes a prefix of one of a few known values
+pub struct KnownValues(pub Vec<String>);
+
+impl Completion for KnownValues {
+    fn get(&self, input: &str) -> Option<String> {
+        if input.is_empty() {
+            return None;
+        }
+
+        let input = input.to_lowercase();
+        self.0
+            .iter()
+            .find(|value| value.to_lowercase().starts_with(&input))
+            .cloned()
+    }
+}
+
+/// Pick a .gitignore template, or "None", typing to narrow the list
+///
+/// GitHub offers hundreds of templates, so the list is searchable rather
+/// than scrolled.
+pub fn select_gitignore(
+    theme: &ColorfulTheme,
+    prompt: &str,
+    templates: &[String],
+    current: Option<&str>,
+) -> Option<String> {
+    let mut options = vec!["None"];
+    options.extend(templates.iter().map(String::as_str));
+
+    let default = current
+        .and_then(|current| options.iter().position(|option| *option == current))
+        .unwrap_or(0);
+
+    let selection = FuzzySelect::with_theme(theme)
+        .with_prompt(prompt)
+        .default(default)
+        .items(&options)
+        .interact()
+        .unwrap();
+
+    if selection == 0 {
+        None
+    } else {
+        Some(options[selection].to_string())
+    }
+}
+
+/// Ask which features new repositories are created without
+///
+/// Takes and returns whether issues, the wiki and projects are turned off, in that order.
+pub fn select_disabled_features(theme: &ColorfulTheme, current: [bool; 3]) -> [bool; 3] {
+    let selected = MultiSelect::with_theme(theme)
+        .with_prompt("Features to turn off in new repositories (space to toggle, enter to confirm)")
+        .items(["Issues", "Wiki", "Projects"])
+        .defaults(&current)
+        .interact()
+        .unwrap();
+
+    [0, 1, 2].map(|index| selected.contains(&index))
+}
+
+/// Ask whether to clone over HTTPS or SSH, returning the `clone_protocol` value
+///
+/// HTTPS is the default, so it's returned as `None`.
+pub fn select_clone_protocol(theme: &ColorfulTheme, ssh: bool) -> Option<String> {
+    let selection = Select::with_theme(theme)
+        .with_prompt("Clone repositories over")
+        .items(["HTTPS", "SSH (needs an SSH key added to GitHub)"])
+        .default(usize::from(ssh))
+        .interact()
+        .unwrap();
+
+    (selection == 1).then(|| "ssh".to_string())
+}
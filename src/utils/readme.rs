@@ -0,0 +1,61 @@
+/// Values substituted into README templates
+pub struct ReadmeContext<'a> {
+    pub name: &'a str,
+    pub description: Option<&'a str>,
+    pub owner: Option<&'a str>,
+    pub license: Option<&'a str>,
+}
+
+const BASIC_TEMPLATE: &str = "# {name}
+
+{description}
+";
+
+const DETAILED_TEMPLATE: &str = "# {name}
+
+{description}
+
+## Getting Started
+
+```bash
+git clone https://github.com/{owner}/{name}.git
+cd {name}
+```
+
+## Usage
+
+Describe how to use {name} here.
+
+## Contributing
+
+Issues and pull requests are welcome.
+
+## License
+
+{license}
+";
+
+/// Render README content for a repository from the named template
+///
+/// Unknown names fall back to `basic`. Supported placeholders: `{name}`,
+/// `{description}`, `{owner}`, `{license}`.
+pub fn render(template: &str, context: &ReadmeContext) -> String {
+    let template = match template {
+        "detailed" => DETAILED_TEMPLATE,
+        _ => BASIC_TEMPLATE,
+    };
+
+    let license = match context.license {
+        Some(license) => format!("Distributed under the {} license.", license),
+        None => "No license has been chosen yet.".to_string(),
+    };
+
+    let rendered = template
+        .replace("{name}", context.name)
+        .replace("{description}", context.description.unwrap_or(""))
+        .replace("{owner}", context.owner.unwrap_or("OWNER"))
+        .replace("{license}", &license);
+
+    // Drop the blank paragraph left behind by a missing description
+    rendered.replace("\n\n\n", "\n\n").trim_end().to_string() + "\n"
+}
@@ -0,0 +1,48 @@
+use crate::utils::config::{self, DEFAULT_GITHUB_HOST, DEFAULT_PROFILE};
+use anyhow::Result;
+use keyring::Entry;
+
+/// Service name tokens are stored under in the OS secret store
+const SERVICE: &str = "repogen";
+
+/// Account name a host's token is stored under
+///
+/// The GitHub username for github.com, `username@host` for other hosts.
+/// Profiles other than the default add a `profile:` prefix so they never
+/// share an entry.
+pub fn account(username: Option<&str>, host: &str) -> String {
+    let username = username.unwrap_or("default");
+
+    let account = if host == DEFAULT_GITHUB_HOST {
+        username.to_string()
+    } else {
+        format!("{}@{}", username, host)
+    };
+
+    match config::active_profile() {
+        DEFAULT_PROFILE => account,
+        profile => format!("{}:{}", profile, account),
+    }
+}
+
+/// Save a token in the OS secret store (Keychain, Secret Service, Credential Manager)
+pub fn store(account: &str, token: &str) -> Result<()> {
+    Entry::new(SERVICE, account)
+        .and_then(|entry| entry.set_password(token))
+        .map_err(Into::into)
+}
+
+/// Read a token from the OS secret store
+pub fn load(account: &str) -> Result<String> {
+    Entry::new(SERVICE, account)
+        .and_then(|entry| entry.get_password())
+        .map_err(Into::into)
+}
+
+/// Remove a token from the OS secret store; a missing entry is not an error
+pub fn delete(account: &str) -> Result<()> {
+    match Entry::new(SERVICE, account).and_then(|entry| entry.delete_credential()) {
+        Ok(()) | Err(keyring::Error::NoEntry) => Ok(()),
+        Err(e) => Err(e.into()),
+    }
+}
@@ -0,0 +1,31 @@
+/// The candidate closest to `input`, if any is close enough to be a likely typo
+///
+/// Compares case-insensitively. A candidate is close enough when at most
+/// half of its characters (and at least two) need changing.
+pub fn closest<'a>(candidates: &'a [String], input: &str) -> Option<&'a str> {
+    let input = input.to_lowercase();
+
+    candidates
+        .iter()
+        .map(|candidate| (candidate, levenshtein(&candidate.to_lowercase(), &input)))
+        .filter(|(candidate, distance)| *distance <= (candidate.chars().count() / 2).max(2))
+        .min_by_key(|(_, distance)| *distance)
+        .map(|(candidate, _)| candidate.as_str())
+}
+
+/// Number of single-character edits turning `a` into `b`
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
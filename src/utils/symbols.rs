@@ -0,0 +1,206 @@
+use std::borrow::Cow;
+use std::env;
+use std::sync::OnceLock;
+
+/// Which glyphs decorative output uses
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum SymbolSet {
+    /// Emoji and Unicode box drawing, as written in the source
+    Emoji,
+    /// Plain ASCII for terminals without Unicode support
+    Ascii,
+    /// Nerd Font icons for terminals using a patched font
+    Nerdfont,
+}
+
+static ACTIVE: OnceLock<SymbolSet> = OnceLock::new();
+
+/// Replacements for every decorative symbol: (emoji, ascii, nerdfont)
+///
+/// An empty ASCII replacement drops the symbol along with the space after it.
+/// Variants with the emoji variation selector come before their bare form.
+const SYMBOLS: &[(&str, &str, &str)] = &[
+    ("✅", "[ok]", "\u{f00c}"),
+    ("✔", "[ok]", "\u{f00c}"),
+    ("☑️", "[ok]", "\u{f046}"),
+    ("❌", "[x]", "\u{f00d}"),
+    ("⚠️", "[!]", "\u{f071}"),
+    ("💡", "[tip]", "\u{f0eb}"),
+    ("ℹ️", "[i]", "\u{f05a}"),
+    ("⏳", "[..]", "\u{f254}"),
+    ("👤", "", "\u{f007}"),
+    ("👥", "", "\u{f0c0}"),
+    ("📋", "", "\u{f0ea}"),
+    ("📁", "", "\u{f07b}"),
+    ("🔐", "", "\u{f023}"),
+    ("🔒", "", "\u{f023}"),
+    ("🚀", "", "\u{f135}"),
+    ("📥", "", "\u{f019}"),
+    ("🎉", "", "\u{f005}"),
+    ("⚙️", "", "\u{f013}"),
+    ("📝", "", "\u{f044}"),
+    ("✏️", "", "\u{f040}"),
+    ("🌐", "", "\u{f0ac}"),
+    ("🌍", "", "\u{f0ac}"),
+    ("📦", "", "\u{f1b2}"),
+    ("👀", "", "\u{f06e}"),
+    ("🔔", "", "\u{f0f3}"),
+    ("🗑️", "", "\u{f1f8}"),
+    ("⏸️", "", "\u{f04c}"),
+    ("⏭️", "", "\u{f051}"),
+    ("📊", "", "\u{f080}"),
+    ("🔍", "", "\u{f002}"),
+    ("⏱️", "", "\u{f017}"),
+    ("📍", "", "\u{f041}"),
+    ("🔗", "", "\u{f0c1}"),
+    ("🛡️", "", "\u{f132}"),
+    ("📌", "", "\u{f08d}"),
+    ("🩺", "", "\u{f0f1}"),
+    ("🚚", "", "\u{f0d1}"),
+    ("🎨", "", "\u{f1fc}"),
+    ("🧪", "", "\u{f0c3}"),
+    ("🏢", "", "\u{f1ad}"),
+    ("🏷️", "", "\u{f02b}"),
+    ("🌿", "", "\u{f06c}"),
+    ("🪝", "", "\u{f120}"),
+    ("📤", "", "\u{f093}"),
+    ("🧩", "", "\u{f12e}"),
+];
+
+/// Box drawing and punctuation that only need replacing for ASCII
+const ASCII_ONLY: &[(&str, &str)] = &[
+    ("━", "="),
+    ("─", "-"),
+    ("┃", "|"),
+    ("│", "|"),
+    ("┏", "+"),
+    ("┓", "+"),
+    ("┗", "+"),
+    ("┛", "+"),
+    ("┌", "+"),
+    ("└", "+"),
+    ("•", "*"),
+    ("→", "->"),
+    ("…", "..."),
+    ("·", "-"),
+];
+
+impl SymbolSet {
+    /// Parse a `symbol_set` config value
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "emoji" => Some(Self::Emoji),
+            "ascii" => Some(Self::Ascii),
+            "nerdfont" => Some(Self::Nerdfont),
+            _ => None,
+        }
+    }
+
+    /// Pick a set for the current terminal
+    ///
+    /// Dumb terminals and classic Windows consoles get ASCII, everything else emoji.
+    pub fn detect() -> Self {
+        let term = env::var("TERM").unwrap_or_default();
+
+        if term == "dumb" || (cfg!(windows) && env::var_os("WT_SESSION").is_none()) {
+            Self::Ascii
+        } else {
+            Self::Emoji
+        }
+    }
+}
+
+/// Choose the symbol set for this run from the configured value
+///
+/// Falls back to detection when unset or unrecognised. Only the first call
+/// has an effect.
+pub fn init(configured: Option<&str>) {
+    let set = configured
+        .and_then(SymbolSet::parse)
+        .unwrap_or_else(SymbolSet::detect);
+    let _ = ACTIVE.set(set);
+}
+
+/// The symbol set in use
+pub fn active() -> SymbolSet {
+    *ACTIVE.get_or_init(SymbolSet::detect)
+}
+
+/// Rewrite decorative symbols in `text` for the active symbol set
+pub fn apply(text: &str) -> Cow<'_, str> {
+    let set = active();
+    if set == SymbolSet::Emoji {
+        return Cow::Borrowed(text);
+    }
+
+    let mut output = text.to_string();
+    for (emoji, ascii, nerdfont) in SYMBOLS {
+        if !output.contains(emoji) {
+            continue;
+        }
+
+        output = match set {
+            SymbolSet::Ascii if ascii.is_empty() => strip_symbol(&output, emoji),
+            SymbolSet::Ascii => output.replace(emoji, ascii),
+            _ => output.replace(emoji, nerdfont),
+        };
+    }
+
+    if set == SymbolSet::Ascii {
+        for (unicode, ascii) in ASCII_ONLY {
+            output = output.replace(unicode, ascii);
+        }
+    }
+
+    Cow::Owned(output)
+}
+
+/// Remove a symbol together with the spaces that separate it from the text
+fn strip_symbol(text: &str, symbol: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(index) = rest.find(symbol) {
+        output.push_str(&rest[..index]);
+        rest = rest[index + symbol.len()..].trim_start_matches(' ');
+    }
+    output.push_str(rest);
+
+    output
+}
+
+/// Spinner frames for progress indicators
+pub fn spinner_ticks() -> &'static str {
+    match active() {
+        SymbolSet::Ascii => "|/-\\ ",
+        _ => "⠁⠂⠄⡀⢀⠠⠐⠈ ",
+    }
+}
+
+/// `println!` that adapts decorative symbols to the active symbol set
+///
+/// Prints nothing once quiet output has been requested.
+#[macro_export]
+macro_rules! outln {
+    () => {
+        if !$crate::utils::output::is_quiet() {
+            println!()
+        }
+    };
+    ($($arg:tt)*) => {
+        if !$crate::utils::output::is_quiet() {
+            println!("{}", $crate::utils::symbols::apply(&format!($($arg)*)))
+        }
+    };
+}
+
+/// `eprintln!` that adapts decorative symbols to the active symbol set
+#[macro_export]
+macro_rules! errln {
+    () => {
+        eprintln!()
+    };
+    ($($arg:tt)*) => {
+        eprintln!("{}", $crate::utils::symbols::apply(&format!($($arg)*)))
+    };
+}
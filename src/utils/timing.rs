@@ -0,0 +1,32 @@
+use std::time::{Duration, Instant};
+
+/// Tracks elapsed time for multi-step operations
+pub struct Timer {
+    started: Instant,
+}
+
+impl Timer {
+    /// Start timing now
+    pub fn start() -> Self {
+        Self {
+            started: Instant::now(),
+        }
+    }
+
+    /// Elapsed time formatted for summary lines, e.g. `34.2s` or `2m 05s`
+    pub fn elapsed(&self) -> String {
+        format_duration(self.started.elapsed())
+    }
+}
+
+/// Format a duration compactly for humans
+pub fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs_f64();
+
+    if secs < 60.0 {
+        format!("{:.1}s", secs)
+    } else {
+        let whole = duration.as_secs();
+        format!("{}m {:02}s", whole / 60, whole % 60)
+    }
+}